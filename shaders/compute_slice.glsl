@@ -88,6 +88,9 @@ void main()
         vec4(0.0)
     };
 
+    // Whether each vertex has been found to lie in the hyperplane.
+    bool touching[4] = { false, false, false, false };
+
     // Loop through all of this tetrahedron's edges.
     for (uint i = 0; i < edge_indices.length(); ++i)
     {
@@ -99,6 +102,18 @@ void main()
 
         if (t >= 0.0 && t <= 1.0)
         {
+            // A vertex that lies in the hyperplane is shared by 3 edges, but it should
+            // only be counted once.
+            if (t == 0.0 || t == 1.0)
+            {
+                uint vertex = (t == 0.0) ? edge.x : edge.y;
+                if (touching[vertex])
+                {
+                    continue;
+                }
+                touching[vertex] = true;
+            }
+
             // Calculate and store the point of intersection.
             vec4 intersection = a + (b - a) * t;
             intersections[slice_id] = intersection;
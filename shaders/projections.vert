@@ -17,7 +17,10 @@ uniform mat4 u_three_view;
 uniform mat4 u_three_projection;
 
 uniform bool u_perspective_4D;
-uniform bool u_perspective_3D;
+
+// Whether to color each vertex by its position (i.e. for wireframes) rather than by
+// the centroid of its cell (which is passed in as the color attribute).
+uniform bool u_shade_by_position;
 
 layout(location = 0) in vec4 position;
 layout(location = 1) in vec4 color;
@@ -44,49 +47,32 @@ vec3 hsv2rgb(vec3 c)
 
 void main()
 {
-    bool perspective_4D = false;
-    bool perspective_3D = true;
-
     vec4 four;
     vs_out.depth_cue = position.w;
 
+    // Transform into the 4D camera's coordinate system: every draw mode uses the same
+    // model matrix (for slices, which are already transformed by the compute shader,
+    // this is the identity).
+    four = u_four_model * position;
+    four = four - u_four_from;
+    four = u_four_view * four;
+
     // Project 4D -> 3D with a perspective projection.
     if (u_perspective_4D)
     {
-        four = u_four_model * position;
-        four = four - u_four_from;
-        four = u_four_view * four;
-
         four = u_four_projection * four;
         four /= four.w;
     }
     // Project 4D -> 3D with a parallel (orthographic) projection.
     else
     {
-        // Simply drop the last (w) coordinate.
-        //four = u_four_model * position;
-        //four = vec4(four.xyz, 1.0);
-
-        // TODO: the code above doesn't always work, since the `u_four_model` matrix (rotations)
-        // TODO: is already applied to the tetrahedra vertices in the compute shader prior to
-        // TODO: generating the 3D slice - we do a standard orthographic projection for this
-        // TODO: draw mode, instead
-        
-        four = vec4(position.xyz, 1.0);
+        // Simply drop the last (depth) coordinate.
+        four = vec4(four.xyz, 1.0);
     }
 
-    vec4 three;
-
-    // Project 3D -> 2D with a perspective projection.
-    if(perspective_3D)
-    {
-        three = u_three_projection * u_three_view * u_three_model * four;
-    }
-    // Project 3D -> 2D with a parallel (orthographic) projection.
-    else
-    {
-        // TODO: is support for a 3D -> 2D orthographic projection useful / necessary?
-    }
+    // Project 3D -> 2D: the 3D camera's projection matrix is either a perspective or a
+    // parallel (orthographic) projection.
+    vec4 three = u_three_projection * u_three_view * u_three_model * four;
 
     gl_Position = three;
     gl_PointSize = 3.0;
@@ -99,9 +85,9 @@ void main()
     vec3 rgb = max(centroid_color, position_color);
 
     // New shading mode (shade wireframes and slices differently)...
-    rgb = u_perspective_4D ? position_color : centroid_color;
+    rgb = u_shade_by_position ? position_color : centroid_color;
     rgb = max(rgb, vec3(0.15));
-    float alpha = u_perspective_4D ? 0.5 : 1.0;
+    float alpha = u_shade_by_position ? 0.5 : 1.0;
 
     // Pass values to fragment shader.
     vs_out.color = vec4(rgb, alpha);
@@ -1,9 +1,9 @@
-use cgmath::{self, InnerSpace, Matrix4, Point3, SquareMatrix, Vector3, Vector4};
+use cgmath::{InnerSpace, Matrix4, Point3, SquareMatrix, Vector3, Vector4, Zero};
 
 use std::f32;
 
 use constants;
-use math::cross;
+use math::{self, cross, Plane};
 
 /// A trait representing a camera object in 3 or 4-space that has a look-at ("view")
 /// and projection matrix.
@@ -14,14 +14,21 @@ pub trait Camera {
     fn build_projection(&mut self);
 }
 
+/// The default field of view of a `FourCamera`, in radians.
+const DEFAULT_FIELD_OF_VIEW: f32 = f32::consts::FRAC_PI_4;
+
+/// The smallest and largest fields of view that a `FourCamera` can have, in radians.
+const FIELD_OF_VIEW_RANGE: (f32, f32) = (0.05, 3.0);
+
 /// A camera that projects objects from 4-space to 3-space.
 pub struct FourCamera {
-    pub from: Vector4<f32>,
-    pub to: Vector4<f32>,
-    pub up: Vector4<f32>,
-    pub over: Vector4<f32>,
-    pub look_at: Matrix4<f32>,
-    pub projection: Matrix4<f32>,
+    from: Vector4<f32>,
+    to: Vector4<f32>,
+    up: Vector4<f32>,
+    over: Vector4<f32>,
+    fov: f32,
+    look_at: Matrix4<f32>,
+    projection: Matrix4<f32>,
 }
 
 impl FourCamera {
@@ -36,6 +43,7 @@ impl FourCamera {
             to,
             up,
             over,
+            fov: DEFAULT_FIELD_OF_VIEW,
             look_at: Matrix4::identity(),
             projection: Matrix4::identity(),
         };
@@ -44,6 +52,101 @@ impl FourCamera {
 
         cam
     }
+
+    pub fn get_from(&self) -> Vector4<f32> {
+        self.from
+    }
+
+    pub fn set_from(&mut self, from: &Vector4<f32>) {
+        self.from = *from;
+        self.build_look_at();
+    }
+
+    pub fn get_to(&self) -> Vector4<f32> {
+        self.to
+    }
+
+    pub fn set_to(&mut self, to: &Vector4<f32>) {
+        self.to = *to;
+        self.build_look_at();
+    }
+
+    pub fn get_up(&self) -> Vector4<f32> {
+        self.up
+    }
+
+    pub fn set_up(&mut self, up: &Vector4<f32>) {
+        self.up = *up;
+        self.build_look_at();
+    }
+
+    pub fn get_over(&self) -> Vector4<f32> {
+        self.over
+    }
+
+    pub fn set_over(&mut self, over: &Vector4<f32>) {
+        self.over = *over;
+        self.build_look_at();
+    }
+
+    /// Returns the (full) field of view of this camera, in radians.
+    pub fn get_fov(&self) -> f32 {
+        self.fov
+    }
+
+    /// Sets the (full) field of view of this camera, in radians. This is clamped to a
+    /// reasonable range, since the projection degenerates at `0` and `π`.
+    pub fn set_fov(&mut self, fov: f32) {
+        self.fov = fov.max(FIELD_OF_VIEW_RANGE.0).min(FIELD_OF_VIEW_RANGE.1);
+        self.build_projection();
+    }
+
+    /// Returns the distance between the eye and the target.
+    pub fn get_distance(&self) -> f32 {
+        (self.from - self.to).magnitude()
+    }
+
+    /// Moves the eye towards or away from the target (along the current viewing
+    /// direction), so that they are `distance` apart.
+    pub fn set_distance(&mut self, distance: f32) {
+        let direction = (self.from - self.to).normalize();
+        self.from = self.to + direction * distance.max(constants::EPSILON);
+        self.build_look_at();
+    }
+
+    /// Orbits the eye around the target by applying `rotation` to the offset between
+    /// them, which moves the eye along the 3-sphere that is centered at the target. The
+    /// `up` and `over` vectors are rotated as well, so that they never become parallel to
+    /// the viewing direction.
+    pub fn orbit(&mut self, rotation: &Matrix4<f32>) {
+        self.from = self.to + rotation * (self.from - self.to);
+        self.up = rotation * self.up;
+        self.over = rotation * self.over;
+        self.build_look_at();
+    }
+
+    /// Orbits the eye around the target by `angle` (in radians), parallel to `plane`. See
+    /// `math::get_simple_rotation_matrix(...)`.
+    pub fn orbit_in_plane(&mut self, plane: Plane, angle: f32) {
+        self.orbit(&math::get_simple_rotation_matrix(plane, angle));
+    }
+
+    /// Moves the eye onto the `axis`th coordinate axis through the target (`0` for the
+    /// x-axis, up to `3` for the w-axis), keeping its current distance from the target.
+    /// The `up` and `over` vectors are set to the next two axes (wrapping around), so
+    /// looking along the x-axis gives `up` along y and `over` along z.
+    pub fn look_along_axis(&mut self, axis: usize) {
+        let unit = |i: usize| {
+            let mut direction = Vector4::zero();
+            direction[i % 4] = 1.0;
+            direction
+        };
+
+        self.from = self.to + unit(axis) * self.get_distance();
+        self.up = unit(axis + 1);
+        self.over = unit(axis + 2);
+        self.build_look_at();
+    }
 }
 
 impl Camera for FourCamera {
@@ -65,17 +168,35 @@ impl Camera for FourCamera {
     }
 
     fn build_projection(&mut self) {
-        let t = 1.0 / (f32::consts::FRAC_PI_4 * 0.5).tan();
+        let t = 1.0 / (self.fov * 0.5).tan();
 
         self.projection = Matrix4::from_diagonal(Vector4::new(t, t, t, t));
     }
 }
 
+/// The (vertical) field of view of a `ThreeCamera` with a perspective projection.
+const THREE_FIELD_OF_VIEW: f32 = f32::consts::FRAC_PI_4;
+
+/// An axis-aligned view that a `ThreeCamera` can be moved to (see `ThreeCamera::set_view(...)`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum View {
+    /// Looking down the negative z-axis, with the y-axis pointing up
+    Front,
+
+    /// Looking down the negative y-axis, with the negative z-axis pointing up
+    Top,
+
+    /// Looking down the negative x-axis, with the y-axis pointing up
+    Side,
+}
+
 /// A camera that projects objects from 3-space to 2-space (the screen).
 pub struct ThreeCamera {
     from: Point3<f32>,
     to: Point3<f32>,
     up: Vector3<f32>,
+    orthographic: bool,
+    scale: f32,
     look_at: Matrix4<f32>,
     projection: Matrix4<f32>,
 }
@@ -86,6 +207,8 @@ impl ThreeCamera {
             from,
             to,
             up,
+            orthographic: false,
+            scale: 1.0,
             look_at: Matrix4::identity(),
             projection: Matrix4::identity(),
         };
@@ -103,6 +226,66 @@ impl ThreeCamera {
         self.from = *from;
         self.build_look_at();
     }
+
+    /// Returns `true` if this camera uses an orthographic (parallel) projection and
+    /// `false` if it uses a perspective projection.
+    pub fn is_orthographic(&self) -> bool {
+        self.orthographic
+    }
+
+    /// Switches between an orthographic (parallel) and a perspective projection. When
+    /// switching to an orthographic projection, the scale is chosen so that objects at
+    /// the target appear to be the same size in both.
+    pub fn set_orthographic(&mut self, orthographic: bool) {
+        if orthographic && !self.orthographic {
+            self.scale = (self.from - self.to).magnitude() * (THREE_FIELD_OF_VIEW * 0.5).tan();
+        }
+        self.orthographic = orthographic;
+        self.build_projection();
+    }
+
+    /// Returns half of the height (in world units) of the region that is visible with an
+    /// orthographic projection.
+    pub fn get_scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Sets half of the height (in world units) of the region that is visible with an
+    /// orthographic projection.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.max(constants::EPSILON);
+        self.build_projection();
+    }
+
+    /// Zooms in (for positive `amount`) or out: with a perspective projection, this moves
+    /// the camera towards its target, and with an orthographic projection (where moving
+    /// the camera wouldn't change the size of anything), this changes the scale instead.
+    pub fn zoom(&mut self, amount: f32) {
+        if self.orthographic {
+            let scale = self.scale - amount;
+            self.set_scale(scale);
+        } else {
+            let offset = self.from - self.to;
+            let distance = (offset.magnitude() - amount).max(constants::EPSILON);
+            self.from = self.to + offset.normalize() * distance;
+            self.build_look_at();
+        }
+    }
+
+    /// Moves the camera to one of the axis-aligned preset `view`s, keeping its current
+    /// distance from the target.
+    pub fn set_view(&mut self, view: View) {
+        let (direction, up) = match view {
+            View::Front => (Vector3::unit_z(), Vector3::unit_y()),
+            View::Top => (Vector3::unit_y(), -Vector3::unit_z()),
+            View::Side => (Vector3::unit_x(), Vector3::unit_y()),
+        };
+
+        let distance = (self.from - self.to).magnitude();
+        self.from = self.to + direction * distance;
+        self.up = up;
+        self.build_look_at();
+    }
 }
 
 impl Camera for ThreeCamera {
@@ -119,8 +302,13 @@ impl Camera for ThreeCamera {
     }
 
     fn build_projection(&mut self) {
-        let fov = cgmath::Rad(std::f32::consts::FRAC_PI_4);
         let aspect = constants::WIDTH as f32 / constants::HEIGHT as f32;
-        self.projection = cgmath::perspective(fov, aspect, 0.1, 1000.0);
+
+        self.projection = if self.orthographic {
+            let (width, height) = (self.scale * aspect, self.scale);
+            cgmath::ortho(-width, width, -height, height, 0.1, 1000.0)
+        } else {
+            cgmath::perspective(cgmath::Rad(THREE_FIELD_OF_VIEW), aspect, 0.1, 1000.0)
+        };
     }
 }
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use cgmath::{InnerSpace, Vector4, Zero};
+
+use math;
+use mesh::Mesh;
+use utilities;
+
+/// The boundary complex of a 4-dimensional polytope: its vertices, edges, (polygonal)
+/// faces, and (polyhedral) cells, along with the incidences between them. Whereas a
+/// `Mesh` only stores flat lists of indices, this answers queries like "which faces
+/// make up this cell?" or "which cells surround this edge?", and provides the cell
+/// adjacency graph (two cells are adjacent if they share a face).
+pub struct BoundaryComplex {
+    /// The vertices of the polytope
+    vertices: Vec<Vector4<f32>>,
+
+    /// The edges of the polytope, as pairs of vertex indices
+    edges: Vec<[u32; 2]>,
+
+    /// The faces of the polytope, as loops of vertex indices (in order around each face)
+    faces: Vec<Vec<u32>>,
+
+    /// The cells of the polytope, as lists of face indices
+    cells: Vec<Vec<u32>>,
+
+    /// The edges that bound each face
+    face_edges: Vec<Vec<u32>>,
+
+    /// The edges that meet at each vertex
+    vertex_edges: Vec<Vec<u32>>,
+
+    /// The faces that meet at each edge
+    edge_faces: Vec<Vec<u32>>,
+
+    /// The cells that each face belongs to (for a closed polytope, there are always two)
+    face_cells: Vec<Vec<u32>>,
+}
+
+impl BoundaryComplex {
+    /// Builds the boundary complex of `mesh`. Meshes that were built directly from
+    /// tetrahedra don't have any faces or cells, in which case this returns `None`.
+    pub fn new(mesh: &Mesh) -> Option<BoundaryComplex> {
+        if mesh.get_cell_faces().is_empty() {
+            return None;
+        }
+
+        let vertices = mesh.get_vertices().clone();
+        let edges = mesh
+            .get_edges()
+            .chunks(2)
+            .map(|pair| [pair[0], pair[1]])
+            .collect::<Vec<_>>();
+
+        let mut edge_lookup = HashMap::new();
+        for (i, [a, b]) in edges.iter().enumerate() {
+            edge_lookup.insert((*a.min(b), *a.max(b)), i as u32);
+        }
+
+        // Faces are stored as unordered lists of vertices, so sort each of them around
+        // its centroid first: then, consecutive vertices are joined by an edge.
+        let faces = mesh
+            .get_faces()
+            .iter()
+            .map(|face| {
+                let points = face
+                    .iter()
+                    .map(|index| vertices[*index as usize])
+                    .collect::<Vec<_>>();
+                math::sort_indices_on_plane(&points)
+                    .iter()
+                    .map(|i| face[*i])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let face_edges = faces
+            .iter()
+            .map(|face| {
+                (0..face.len())
+                    .filter_map(|k| {
+                        let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                        edge_lookup.get(&(a.min(b), a.max(b))).cloned()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut vertex_edges = vec![Vec::new(); vertices.len()];
+        for (i, edge) in edges.iter().enumerate() {
+            for vertex in edge.iter() {
+                vertex_edges[*vertex as usize].push(i as u32);
+            }
+        }
+
+        let mut edge_faces = vec![Vec::new(); edges.len()];
+        for (i, edges_of_face) in face_edges.iter().enumerate() {
+            for edge in edges_of_face.iter() {
+                edge_faces[*edge as usize].push(i as u32);
+            }
+        }
+
+        let cells = mesh.get_cell_faces().clone();
+        let mut face_cells = vec![Vec::new(); faces.len()];
+        for (i, cell) in cells.iter().enumerate() {
+            for face in cell.iter() {
+                face_cells[*face as usize].push(i as u32);
+            }
+        }
+
+        Some(BoundaryComplex {
+            vertices,
+            edges,
+            faces,
+            cells,
+            face_edges,
+            vertex_edges,
+            edge_faces,
+            face_cells,
+        })
+    }
+
+    /// Returns the number of vertices in this complex.
+    pub fn get_number_of_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges in this complex.
+    pub fn get_number_of_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the number of faces in this complex.
+    pub fn get_number_of_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// Returns the number of cells in this complex.
+    pub fn get_number_of_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the `i`th vertex.
+    pub fn get_vertex(&self, i: u32) -> Vector4<f32> {
+        self.vertices[i as usize]
+    }
+
+    /// Returns the indices of the two vertices of the `i`th edge.
+    pub fn get_edge(&self, i: u32) -> [u32; 2] {
+        self.edges[i as usize]
+    }
+
+    /// Returns the indices of the vertices of the `i`th face, in order around the face.
+    pub fn get_face(&self, i: u32) -> &[u32] {
+        &self.faces[i as usize]
+    }
+
+    /// Returns the indices of the edges that bound the `i`th face, in order around the face.
+    pub fn get_edges_of_face(&self, i: u32) -> &[u32] {
+        &self.face_edges[i as usize]
+    }
+
+    /// Returns the indices of the faces of the `i`th cell.
+    pub fn get_faces_of_cell(&self, i: u32) -> &[u32] {
+        &self.cells[i as usize]
+    }
+
+    /// Returns the (sorted) indices of the edges of the `i`th cell.
+    pub fn get_edges_of_cell(&self, i: u32) -> Vec<u32> {
+        let mut edges = self.cells[i as usize]
+            .iter()
+            .flat_map(|face| self.face_edges[*face as usize].iter().cloned())
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges.dedup();
+        edges
+    }
+
+    /// Returns the (sorted) indices of the vertices of the `i`th cell.
+    pub fn get_vertices_of_cell(&self, i: u32) -> Vec<u32> {
+        let mut vertices = self.cells[i as usize]
+            .iter()
+            .flat_map(|face| self.faces[*face as usize].iter().cloned())
+            .collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    /// Returns the centroid of the `i`th cell (the average of its vertices).
+    pub fn get_cell_centroid(&self, i: u32) -> Vector4<f32> {
+        let vertices = self
+            .get_vertices_of_cell(i)
+            .iter()
+            .map(|vertex| self.vertices[*vertex as usize])
+            .collect::<Vec<_>>();
+        utilities::average(&vertices, &Vector4::zero())
+    }
+
+    /// Returns the (outward-facing) unit normal of the hyperplane that contains the `i`th
+    /// cell, assuming that the polytope is convex.
+    pub fn get_cell_normal(&self, i: u32) -> Vector4<f32> {
+        let face = &self.faces[self.cells[i as usize][0] as usize];
+        let a = self.vertices[face[0] as usize];
+        let b = self.vertices[face[1] as usize];
+        let c = self.vertices[face[2] as usize];
+
+        // The normal is orthogonal to the plane of any face, as well as the direction from
+        // that face towards the centroid of the cell.
+        let centroid = self.get_cell_centroid(i);
+        let normal = math::cross(&(b - a), &(c - a), &(centroid - a)).normalize();
+
+        if normal.dot(centroid - utilities::average(&self.vertices, &Vector4::zero())) < 0.0 {
+            -normal
+        } else {
+            normal
+        }
+    }
+
+    /// Returns the indices of the edges that meet at the `i`th vertex.
+    pub fn get_edges_around_vertex(&self, i: u32) -> &[u32] {
+        &self.vertex_edges[i as usize]
+    }
+
+    /// Returns the indices of the faces that meet at the `i`th edge.
+    pub fn get_faces_around_edge(&self, i: u32) -> &[u32] {
+        &self.edge_faces[i as usize]
+    }
+
+    /// Returns the (sorted) indices of the cells that meet at the `i`th edge.
+    pub fn get_cells_around_edge(&self, i: u32) -> Vec<u32> {
+        let mut cells = self.edge_faces[i as usize]
+            .iter()
+            .flat_map(|face| self.face_cells[*face as usize].iter().cloned())
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells.dedup();
+        cells
+    }
+
+    /// Returns the (sorted) indices of the cells that meet at the `i`th vertex.
+    pub fn get_cells_around_vertex(&self, i: u32) -> Vec<u32> {
+        let mut cells = self.vertex_edges[i as usize]
+            .iter()
+            .flat_map(|edge| self.get_cells_around_edge(*edge))
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells.dedup();
+        cells
+    }
+
+    /// Returns the indices of the cells that the `i`th face belongs to.
+    pub fn get_cells_of_face(&self, i: u32) -> &[u32] {
+        &self.face_cells[i as usize]
+    }
+
+    /// Returns the cells that are adjacent to the `i`th cell, along with the index of
+    /// the face that each of them shares with it.
+    pub fn get_cell_neighbors(&self, i: u32) -> Vec<(u32, u32)> {
+        self.cells[i as usize]
+            .iter()
+            .flat_map(|face| {
+                self.face_cells[*face as usize]
+                    .iter()
+                    .filter(|cell| **cell != i)
+                    .map(move |cell| (*cell, *face))
+            })
+            .collect()
+    }
+
+    /// Returns the cell adjacency graph: the `i`th entry is the (sorted) list of cells
+    /// that share a face with the `i`th cell.
+    pub fn get_adjacency_graph(&self) -> Vec<Vec<u32>> {
+        (0..self.cells.len() as u32)
+            .map(|i| {
+                let mut neighbors = self
+                    .get_cell_neighbors(i)
+                    .iter()
+                    .map(|(cell, _)| *cell)
+                    .collect::<Vec<_>>();
+                neighbors.sort();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect()
+    }
+}
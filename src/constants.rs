@@ -3,4 +3,7 @@ pub const HEIGHT: u32 = 600;
 pub const EPSILON: f32 = 0.001;
 pub const MOUSE_SENSITIVITY: f32 = 3.0;
 pub const ZOOM_INCREMENT: f32 = 0.2;
+pub const FOV_INCREMENT: f32 = 0.05;
 pub const W_DEPTH_RANGE: f32 = 2.0;
+pub const UNFOLD_SPEED: f32 = 0.5;
+pub const STEREOGRAPHIC_SEGMENTS: usize = 16;
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use cgmath::{InnerSpace, Vector3, Zero};
+
+use hyperplane::Hyperplane;
+use mesh::Mesh;
+use weld::{self, Welder};
+
+/// The file formats that a cross-section can be exported to.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    /// Wavefront OBJ (with an accompanying MTL file for the per-cell colors)
+    Obj,
+
+    /// ASCII PLY (with per-face colors)
+    Ply,
+
+    /// Binary STL (with per-facet colors, using the VisCAM / SolidView convention)
+    Stl,
+}
+
+impl Format {
+    /// Returns the format that corresponds to the extension of `path`, if any.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "obj" => Some(Format::Obj),
+            "ply" => Some(Format::Ply),
+            "stl" => Some(Format::Stl),
+            _ => None,
+        }
+    }
+
+    /// Returns the file extension for this format.
+    pub fn get_extension(&self) -> &'static str {
+        match *self {
+            Format::Obj => "obj",
+            Format::Ply => "ply",
+            Format::Stl => "stl",
+        }
+    }
+}
+
+/// A welded, indexed triangle mesh of the 3-dimensional cross-section of a `Mesh`, which
+/// can be written to disk (i.e. for 3D printing or for use in other modeling software).
+///
+/// Like the slices that are drawn by the viewer, the cross-section is embedded in 3D by
+/// dropping the `w`-coordinate of each point.
+pub struct CrossSection {
+    /// The unique vertices of the cross-section
+    vertices: Vec<Vector3<f32>>,
+
+    /// The triangles of the cross-section, sorted by cell
+    triangles: Vec<[u32; 3]>,
+
+    /// The index of the cell that each triangle belongs to
+    cell_indices: Vec<u32>,
+
+    /// The color of each cell that was cut by the hyperplane
+    cell_colors: BTreeMap<u32, Vector3<f32>>,
+}
+
+impl CrossSection {
+    /// Computes the cross-section of `mesh` (taking into account its current transform)
+    /// with `hyperplane` on the CPU.
+    pub fn new(mesh: &Mesh, hyperplane: &Hyperplane) -> CrossSection {
+        let mut welder = Welder::new();
+        let mut triangles = Vec::new();
+        let mut cell_colors = BTreeMap::new();
+
+        for (tetrahedron, slice) in mesh.get_tetrahedra().iter().zip(mesh.slice(hyperplane)) {
+            for triangle in slice.get_triangles().iter() {
+                let indices = [
+                    welder.insert(triangle[0]),
+                    welder.insert(triangle[1]),
+                    welder.insert(triangle[2]),
+                ];
+
+                // Skip triangles that collapsed after welding.
+                if indices[0] == indices[1] || indices[1] == indices[2] || indices[2] == indices[0]
+                {
+                    continue;
+                }
+                triangles.push((tetrahedron.cell_index, indices));
+
+                // This matches the way that slices are colored in `projections.vert`.
+                let centroid = tetrahedron.cell_centroid.truncate();
+                cell_colors
+                    .entry(tetrahedron.cell_index)
+                    .or_insert_with(|| {
+                        let color = centroid.normalize() * 0.5 + Vector3::new(0.5, 0.5, 0.5);
+                        Vector3::new(color.x.max(0.15), color.y.max(0.15), color.z.max(0.15))
+                    });
+            }
+        }
+
+        // Group the triangles by cell (this is a stable sort, so the original ordering is
+        // preserved within each cell).
+        triangles.sort_by_key(|(cell_index, _)| *cell_index);
+
+        let mut cross_section = CrossSection {
+            vertices: welder
+                .into_vertices()
+                .iter()
+                .map(|vertex| vertex.truncate())
+                .collect(),
+            cell_indices: triangles
+                .iter()
+                .map(|(cell_index, _)| *cell_index)
+                .collect(),
+            triangles: triangles.iter().map(|(_, indices)| *indices).collect(),
+            cell_colors,
+        };
+        weld::orient(&cross_section.vertices, &mut cross_section.triangles);
+        cross_section
+    }
+
+    /// Returns the unique vertices of this cross-section.
+    pub fn get_vertices(&self) -> &Vec<Vector3<f32>> {
+        &self.vertices
+    }
+
+    /// Returns the triangles of this cross-section, each of which is a triple of indices
+    /// into `get_vertices(...)`.
+    pub fn get_triangles(&self) -> &Vec<[u32; 3]> {
+        &self.triangles
+    }
+
+    /// Returns the index of the cell that each triangle belongs to.
+    pub fn get_cell_indices(&self) -> &Vec<u32> {
+        &self.cell_indices
+    }
+
+    /// Returns the (unit) normal vector of the `i`th triangle.
+    pub fn get_normal(&self, i: usize) -> Vector3<f32> {
+        let [a, b, c] = self.get_triangle_vertices(i);
+        let normal = (b - a).cross(c - a);
+
+        if normal.magnitude2() > 0.0 {
+            normal.normalize()
+        } else {
+            Vector3::zero()
+        }
+    }
+
+    fn get_triangle_vertices(&self, i: usize) -> [Vector3<f32>; 3] {
+        let [a, b, c] = self.triangles[i];
+        [
+            self.vertices[a as usize],
+            self.vertices[b as usize],
+            self.vertices[c as usize],
+        ]
+    }
+
+    /// Returns the ranges of triangles that belong to each cell, along with the cell's
+    /// index.
+    fn get_groups(&self) -> Vec<(u32, usize, usize)> {
+        let mut groups: Vec<(u32, usize, usize)> = Vec::new();
+
+        for (i, cell_index) in self.cell_indices.iter().enumerate() {
+            match groups.last_mut() {
+                Some(group) if group.0 == *cell_index => group.2 = i + 1,
+                _ => groups.push((*cell_index, i, i + 1)),
+            }
+        }
+
+        groups
+    }
+
+    /// Writes this cross-section to `path`, in the format that corresponds to its
+    /// extension. For OBJ files, a MTL file (with the same name) is written alongside
+    /// it.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = Format::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported file extension: {}", path.display()),
+            )
+        })?;
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        match format {
+            Format::Obj => {
+                let mtl_path = path.with_extension("mtl");
+                let mut mtl_writer = BufWriter::new(File::create(&mtl_path)?);
+                self.write_mtl(&mut mtl_writer)?;
+
+                let mtl_name = mtl_path.file_name().and_then(|name| name.to_str());
+                self.write_obj(&mut writer, mtl_name)
+            }
+            Format::Ply => self.write_ply(&mut writer),
+            Format::Stl => self.write_stl(&mut writer),
+        }
+    }
+
+    /// Writes this cross-section as a Wavefront OBJ file, with one group (and material)
+    /// per cell. If `material_library` is provided, the file will reference it (see
+    /// `write_mtl(...)`).
+    pub fn write_obj<W: Write>(
+        &self,
+        writer: &mut W,
+        material_library: Option<&str>,
+    ) -> io::Result<()> {
+        writeln!(writer, "# Cross-section exported by four")?;
+        if let Some(name) = material_library {
+            writeln!(writer, "mtllib {}", name)?;
+        }
+
+        for vertex in self.vertices.iter() {
+            writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+
+        for (cell_index, start, end) in self.get_groups() {
+            writeln!(writer, "g cell_{}", cell_index)?;
+            if material_library.is_some() {
+                writeln!(writer, "usemtl cell_{}", cell_index)?;
+            }
+
+            // Note that OBJ indices are 1-based.
+            for triangle in self.triangles[start..end].iter() {
+                writeln!(
+                    writer,
+                    "f {} {} {}",
+                    triangle[0] + 1,
+                    triangle[1] + 1,
+                    triangle[2] + 1
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a Wavefront MTL file with one (diffuse) material per cell, to be used along
+    /// with `write_obj(...)`.
+    pub fn write_mtl<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (cell_index, color) in self.cell_colors.iter() {
+            writeln!(writer, "newmtl cell_{}", cell_index)?;
+            writeln!(writer, "Kd {} {} {}", color.x, color.y, color.z)?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes this cross-section as an ASCII PLY file. Each face has a color and the index
+    /// of the cell that it belongs to.
+    pub fn write_ply<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format ascii 1.0")?;
+        writeln!(writer, "comment Cross-section exported by four")?;
+        writeln!(writer, "element vertex {}", self.vertices.len())?;
+        writeln!(writer, "property float x")?;
+        writeln!(writer, "property float y")?;
+        writeln!(writer, "property float z")?;
+        writeln!(writer, "element face {}", self.triangles.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+        writeln!(writer, "property uchar red")?;
+        writeln!(writer, "property uchar green")?;
+        writeln!(writer, "property uchar blue")?;
+        writeln!(writer, "property int cell")?;
+        writeln!(writer, "end_header")?;
+
+        for vertex in self.vertices.iter() {
+            writeln!(writer, "{} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+
+        for (triangle, cell_index) in self.triangles.iter().zip(self.cell_indices.iter()) {
+            let color = self.cell_colors[cell_index] * 255.0;
+            writeln!(
+                writer,
+                "3 {} {} {} {} {} {} {}",
+                triangle[0],
+                triangle[1],
+                triangle[2],
+                color.x.round() as u8,
+                color.y.round() as u8,
+                color.z.round() as u8,
+                cell_index
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes this cross-section as a binary STL file. The color of each facet is stored
+    /// in its "attribute byte count", using the VisCAM / SolidView convention (5 bits per
+    /// channel, with the high bit set to indicate that the color is valid).
+    pub fn write_stl<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut header = [0u8; 80];
+        let description = b"Cross-section exported by four";
+        header[..description.len()].copy_from_slice(description);
+        writer.write_all(&header)?;
+        writer.write_all(&(self.triangles.len() as u32).to_le_bytes())?;
+
+        let write_vector = |writer: &mut W, vector: &Vector3<f32>| -> io::Result<()> {
+            for component in [vector.x, vector.y, vector.z].iter() {
+                writer.write_all(&component.to_le_bytes())?;
+            }
+            Ok(())
+        };
+
+        for (i, cell_index) in self.cell_indices.iter().enumerate() {
+            write_vector(writer, &self.get_normal(i))?;
+            for vertex in self.get_triangle_vertices(i).iter() {
+                write_vector(writer, vertex)?;
+            }
+
+            let color = self.cell_colors[cell_index] * 31.0;
+            let attribute = 0x8000
+                | ((color.x.round() as u16) << 10)
+                | ((color.y.round() as u16) << 5)
+                | (color.z.round() as u16);
+            writer.write_all(&attribute.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,441 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cgmath::{InnerSpace, Vector4, Zero};
+
+use constants;
+use hyperplane::Hyperplane;
+use math;
+use polychora::{Definition, Polytope};
+use utilities;
+
+/// How close a point needs to be to a hyperplane in order to be considered as lying
+/// "on" it while building a hull (unless a different tolerance is given: see
+/// `ConvexHull::with_tolerance(...)`). This is much smaller than `constants::EPSILON`,
+/// since large random point sets (i.e. on the 3-sphere) will often have neighboring facets
+/// that are very nearly, but not quite, coplanar.
+pub const TOLERANCE: f32 = 1.0e-5;
+
+/// A simplicial facet (a tetrahedron) on the boundary of a convex hull that is
+/// under construction.
+struct Facet {
+    /// The indices of the 4 points that make up this facet
+    indices: [usize; 4],
+
+    /// The outward-facing unit normal of this facet
+    normal: Vector4<f32>,
+
+    /// The magnitude of the 4D cross product of this facet's edges (6 times its volume):
+    /// the normals of thin "sliver" facets are poorly conditioned
+    size: f32,
+
+    /// The distance from the origin to the hyperplane that contains this facet, such
+    /// that `normal.dot(x) == offset` for all points `x` in the facet
+    offset: f32,
+}
+
+impl Facet {
+    /// Creates a new facet from 4 points, orienting its normal so that it faces away
+    /// from `interior` (a point that is known to lie strictly inside the hull). Returns
+    /// `None` if the points are (nearly) coplanar, i.e. the size of the facet is within
+    /// `tolerance` of zero, since its normal would be meaningless.
+    fn new(
+        indices: [usize; 4],
+        points: &[Vector4<f32>],
+        interior: &Vector4<f32>,
+        tolerance: f32,
+    ) -> Option<Facet> {
+        let a = points[indices[0]];
+        let orthogonal = math::cross(
+            &(points[indices[1]] - a),
+            &(points[indices[2]] - a),
+            &(points[indices[3]] - a),
+        );
+        let size = orthogonal.magnitude();
+        if size <= tolerance {
+            return None;
+        }
+        let mut normal = orthogonal / size;
+
+        if normal.dot(interior - a) > 0.0 {
+            normal = -normal;
+        }
+
+        Some(Facet {
+            indices,
+            normal,
+            size,
+            offset: normal.dot(a),
+        })
+    }
+
+    /// Returns the signed distance from `point` to the hyperplane containing this
+    /// facet: this will be positive if `point` is "above" (i.e. visible from) the facet.
+    fn distance(&self, point: &Vector4<f32>) -> f32 {
+        self.normal.dot(*point) - self.offset
+    }
+
+    /// Returns the 4 ridges (triangles) of this facet, each of which is sorted so that
+    /// it can be used as a key.
+    fn ridges(&self) -> [[usize; 3]; 4] {
+        let [a, b, c, d] = self.indices;
+        let mut ridges = [[a, b, c], [a, b, d], [a, c, d], [b, c, d]];
+        for ridge in ridges.iter_mut() {
+            ridge.sort();
+        }
+
+        ridges
+    }
+}
+
+/// Returns `true` if all of the components of `point` are finite (i.e. not NaN or infinite).
+fn is_finite(point: &Vector4<f32>) -> bool {
+    point.x.is_finite() && point.y.is_finite() && point.z.is_finite() && point.w.is_finite()
+}
+
+/// Given a set of `points`, returns the indices of 5 points that form a non-degenerate
+/// 4-simplex, or `None` if all of the points lie in a common 3-dimensional hyperplane.
+/// Points that aren't finite are ignored.
+///
+/// Each successive point is chosen to be the one that is furthest from the affine hull
+/// of the points chosen so far.
+fn find_initial_simplex(points: &[Vector4<f32>]) -> Option<[usize; 5]> {
+    let first = points.iter().position(is_finite)?;
+
+    let mut simplex = [first; 5];
+    let mut basis: Vec<Vector4<f32>> = Vec::new();
+
+    for i in 1..5 {
+        let origin = points[simplex[0]];
+
+        // Returns the component of `point - origin` that is orthogonal to all of the
+        // directions spanned so far.
+        let residual = |point: &Vector4<f32>| {
+            let mut d = point - origin;
+            for direction in basis.iter() {
+                d -= direction * direction.dot(d);
+            }
+            d
+        };
+
+        let (index, largest) = points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| is_finite(point))
+            .map(|(index, point)| (index, residual(point)))
+            .max_by(|a, b| a.1.magnitude2().total_cmp(&b.1.magnitude2()))
+            .unwrap();
+
+        if largest.magnitude() <= constants::EPSILON {
+            return None;
+        }
+
+        simplex[i] = index;
+        basis.push(largest.normalize());
+    }
+
+    Some(simplex)
+}
+
+/// Computes the boundary of the convex hull of `points` as a list of simplicial facets,
+/// using an incremental ("beneath-beyond") algorithm. Points that aren't finite are
+/// ignored.
+///
+/// Points are added to the hull one at a time. Each new point "sees" some number of
+/// facets of the current hull: these are removed, and the hole that they leave behind
+/// is patched by connecting the new point to each of the ridges along the boundary of
+/// the hole (the "horizon"). Points that lie within `tolerance` of a facet's hyperplane
+/// are treated as not seeing that facet, which means that the non-simplicial cells of
+/// polytopes like the 8-cell end up split into several coplanar facets.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Convex_hull_algorithms#Higher_dimensions`
+fn build_facets(points: &[Vector4<f32>], tolerance: f32) -> Vec<Facet> {
+    let simplex = match find_initial_simplex(points) {
+        Some(simplex) => simplex,
+        None => return Vec::new(),
+    };
+
+    let interior = utilities::average(
+        &simplex.iter().map(|i| points[*i]).collect::<Vec<_>>(),
+        &Vector4::zero(),
+    );
+
+    let mut facets = Vec::new();
+    for skip in 0..5 {
+        let mut indices = [0; 4];
+        let mut j = 0;
+        for (k, index) in simplex.iter().enumerate() {
+            if k != skip {
+                indices[j] = *index;
+                j += 1;
+            }
+        }
+        facets.extend(Facet::new(indices, points, &interior, tolerance));
+    }
+
+    for (index, point) in points.iter().enumerate() {
+        if simplex.contains(&index) || !is_finite(point) {
+            continue;
+        }
+
+        let (visible, hidden): (Vec<Facet>, Vec<Facet>) = facets
+            .into_iter()
+            .partition(|facet| facet.distance(point) > tolerance);
+        facets = hidden;
+
+        if visible.is_empty() {
+            // This point is inside of (or on the boundary of) the current hull.
+            continue;
+        }
+
+        // Any ridge that belongs to exactly one visible facet is on the horizon.
+        let mut counts: BTreeMap<[usize; 3], usize> = BTreeMap::new();
+        for facet in visible.iter() {
+            for ridge in facet.ridges().iter() {
+                *counts.entry(*ridge).or_insert(0) += 1;
+            }
+        }
+
+        for (ridge, count) in counts.iter() {
+            if *count == 1 {
+                facets.extend(Facet::new(
+                    [ridge[0], ridge[1], ridge[2], index],
+                    points,
+                    &interior,
+                    tolerance,
+                ));
+            }
+        }
+    }
+
+    facets
+}
+
+/// Groups the simplicial `facets` of a convex hull by the hyperplanes that contain them,
+/// returning one hyperplane per cell along with the (sorted) indices of all of the points
+/// that lie on that cell.
+///
+/// A facet belongs to a cell if all of its points lie within `tolerance` of the cell's
+/// hyperplane. Comparing normals instead would split cells apart, since the normals of
+/// thin facets can be far off even when their points are (nearly) coplanar. For the same
+/// reason, the largest facets are visited first, so that each cell's hyperplane comes
+/// from its best-conditioned facet.
+fn merge_facets(
+    points: &[Vector4<f32>],
+    facets: &[Facet],
+    tolerance: f32,
+) -> Vec<(Hyperplane, Vec<usize>)> {
+    let mut cells: Vec<(Hyperplane, Vec<usize>)> = Vec::new();
+
+    let mut sorted = facets.iter().collect::<Vec<_>>();
+    sorted.sort_by(|a, b| b.size.total_cmp(&a.size));
+
+    for facet in sorted {
+        let existing = cells.iter_mut().find(|(other, _)| {
+            other.normal.dot(facet.normal) > 0.0
+                && facet
+                    .indices
+                    .iter()
+                    .all(|index| other.signed_distance(&points[*index]).abs() <= tolerance)
+        });
+
+        match existing {
+            Some((_, indices)) => indices.extend_from_slice(&facet.indices),
+            None => cells.push((
+                Hyperplane::new(facet.normal, -facet.offset),
+                facet.indices.to_vec(),
+            )),
+        }
+    }
+
+    for (_, indices) in cells.iter_mut() {
+        indices.sort();
+        indices.dedup();
+    }
+
+    cells
+}
+
+/// Returns the number of linearly independent vectors in `vectors`.
+fn rank(vectors: &[Vector4<f32>]) -> usize {
+    let mut basis: Vec<Vector4<f32>> = Vec::new();
+
+    for vector in vectors.iter() {
+        let mut residual = *vector;
+        for direction in basis.iter() {
+            residual -= direction * direction.dot(residual);
+        }
+        if residual.magnitude() > constants::EPSILON {
+            basis.push(residual.normalize());
+        }
+    }
+
+    basis.len()
+}
+
+/// Returns the hyperplanes that bound the convex hull of `points` (its H-representation),
+/// with normals that point away from the interior of the hull. Each hyperplane corresponds
+/// to exactly one cell of the hull.
+///
+/// Coplanar simplicial facets are merged, so that (for example) the 8 cubic cells of
+/// the 8-cell produce exactly 8 hyperplanes.
+pub fn get_bounding_hyperplanes(points: &[Vector4<f32>]) -> Vec<Hyperplane> {
+    merge_facets(points, &build_facets(points, TOLERANCE), TOLERANCE)
+        .into_iter()
+        .map(|(hyperplane, _)| hyperplane)
+        .collect()
+}
+
+/// The convex hull of a set of points in 4-dimensions, stored in the same layout that
+/// a `Mesh` uses: a list of vertices, a flat list of edge indices, and a list of faces.
+/// Additionally, the hull knows which faces belong to each of its cells.
+pub struct ConvexHull {
+    /// The vertices of the hull (i.e. the extreme points of the original point set)
+    vertices: Vec<Vector4<f32>>,
+
+    /// The edges of the hull, as pairs of vertex indices
+    edges: Vec<u32>,
+
+    /// The faces of the hull, each of which is a list of vertex indices (in order)
+    faces: Vec<Vec<u32>>,
+
+    /// The cells of the hull, each of which is a list of face indices
+    cells: Vec<Vec<u32>>,
+
+    /// The hyperplanes that bound each of the cells of the hull
+    h_representation: Vec<Hyperplane>,
+}
+
+impl ConvexHull {
+    /// Computes the convex hull of `points`. Any points that are not extreme points of
+    /// the hull (i.e. points inside of the hull or in the interior of one of its cells,
+    /// faces, or edges) are discarded. If all of the points lie in a common 3-dimensional
+    /// hyperplane, the resulting hull will be empty.
+    pub fn new(points: &[Vector4<f32>]) -> ConvexHull {
+        ConvexHull::with_tolerance(points, TOLERANCE)
+    }
+
+    /// Computes the convex hull of `points`, where points that are within `tolerance` of
+    /// a hyperplane are considered to lie on it. A larger tolerance is useful for points
+    /// that are known to lie on common cells, but have accumulated some rounding error
+    /// (i.e. points that were derived from the vertices of another polytope).
+    pub fn with_tolerance(points: &[Vector4<f32>], tolerance: f32) -> ConvexHull {
+        let cells = merge_facets(points, &build_facets(points, tolerance), tolerance);
+
+        // A point is a vertex of the hull if the normals of the cells that it touches
+        // span all of 4-space: points that lie inside of a cell, face, or edge only
+        // touch cells whose normals span 1, 2, or 3 dimensions, respectively.
+        let mut incident_cells: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (cell_index, (_, indices)) in cells.iter().enumerate() {
+            for index in indices.iter() {
+                incident_cells.entry(*index).or_default().push(cell_index);
+            }
+        }
+        incident_cells.retain(|_, cell_indices| {
+            let normals = cell_indices
+                .iter()
+                .map(|cell_index| cells[*cell_index].0.normal)
+                .collect::<Vec<_>>();
+            rank(&normals) == 4
+        });
+
+        // Assign new (compact) indices to each of the extreme points.
+        let remap = incident_cells
+            .keys()
+            .enumerate()
+            .map(|(new_index, old_index)| (*old_index, new_index as u32))
+            .collect::<BTreeMap<_, _>>();
+        let vertices = incident_cells
+            .keys()
+            .map(|index| points[*index])
+            .collect::<Vec<_>>();
+
+        // Two cells meet at a face if the vertices that they share span a plane.
+        let mut shared: BTreeMap<(usize, usize), Vec<u32>> = BTreeMap::new();
+        for (old_index, cell_indices) in incident_cells.iter() {
+            for (i, a) in cell_indices.iter().enumerate() {
+                for b in cell_indices.iter().skip(i + 1) {
+                    shared.entry((*a, *b)).or_default().push(remap[old_index]);
+                }
+            }
+        }
+
+        let mut faces = Vec::new();
+        let mut cell_faces = vec![Vec::new(); cells.len()];
+        for ((a, b), indices) in shared.iter() {
+            let face_vertices = indices
+                .iter()
+                .map(|index| vertices[*index as usize])
+                .collect::<Vec<_>>();
+            let origin = face_vertices[0];
+            let directions = face_vertices
+                .iter()
+                .map(|vertex| vertex - origin)
+                .collect::<Vec<_>>();
+
+            if rank(&directions) == 2 {
+                let face_index = faces.len() as u32;
+                faces.push(
+                    math::sort_indices_on_plane(&face_vertices)
+                        .iter()
+                        .map(|i| indices[*i])
+                        .collect::<Vec<_>>(),
+                );
+                cell_faces[*a].push(face_index);
+                cell_faces[*b].push(face_index);
+            }
+        }
+
+        // The edges of the hull are the sides of its (sorted) faces.
+        let mut unique_edges = BTreeSet::new();
+        for face in faces.iter() {
+            for (i, a) in face.iter().enumerate() {
+                let b = face[(i + 1) % face.len()];
+                unique_edges.insert((*a.min(&b), *a.max(&b)));
+            }
+        }
+        let edges = unique_edges
+            .iter()
+            .flat_map(|(a, b)| vec![*a, *b])
+            .collect::<Vec<_>>();
+
+        ConvexHull {
+            vertices,
+            edges,
+            faces,
+            cells: cell_faces,
+            h_representation: cells
+                .into_iter()
+                .map(|(hyperplane, _)| hyperplane)
+                .collect(),
+        }
+    }
+
+    /// Returns a list of the cells of this hull, where each cell is a list of indices into
+    /// the array returned by `get_faces(...)`. The `i`th cell lies in the `i`th hyperplane
+    /// of the hull's H-representation.
+    pub fn get_cells(&self) -> Vec<Vec<u32>> {
+        self.cells.clone()
+    }
+}
+
+impl Polytope for ConvexHull {
+    fn get_definition(&self) -> Definition {
+        Definition::from_topology(&self.faces, &self.cells)
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        self.vertices.clone()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        self.edges.clone()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
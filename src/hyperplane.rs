@@ -1,10 +1,12 @@
-use cgmath::{self, InnerSpace, Vector4};
+use cgmath::{InnerSpace, Vector4};
 
 use constants;
 
 /// A 4-dimensional hyperplane, specified in Hessian normal form:
 ///
-///     n.dot(x) = -d
+/// ```text
+/// n.dot(x) = -d
+/// ```
 ///
 /// where `d` is the distance of the hyperplane from the origin. Here, the sign
 /// of `d` determines the side of the plane on which the origin is located. If
@@ -1,4 +1,4 @@
-use cgmath::{self, Vector2, Zero};
+use cgmath::{Vector2, Zero};
 
 /// A simple struct for managing interaction state.
 pub struct InteractionState {
@@ -22,6 +22,9 @@ pub struct InteractionState {
 
     /// Whether or not the control key is pressed
     pub ctrl_pressed: bool,
+
+    /// Whether or not the alt key is pressed
+    pub alt_pressed: bool,
 }
 
 impl InteractionState {
@@ -34,6 +37,7 @@ impl InteractionState {
             rmouse_pressed: false,
             shift_pressed: false,
             ctrl_pressed: false,
+            alt_pressed: false,
         }
     }
 
@@ -0,0 +1,36 @@
+extern crate cgmath;
+
+#[cfg(feature = "rendering")]
+extern crate gl;
+#[cfg(feature = "rendering")]
+extern crate image;
+
+// Geometry modules, which do not depend on OpenGL.
+pub mod camera;
+pub mod complex;
+pub mod constants;
+pub mod export;
+pub mod hull;
+pub mod hyperplane;
+pub mod math;
+pub mod measure;
+pub mod mesh;
+pub mod operators;
+pub mod polychora;
+pub mod primitives;
+pub mod prism;
+pub mod schlegel;
+pub mod shape_file;
+pub mod stereographic;
+pub mod tetrahedron;
+pub mod topology;
+pub mod unfold;
+pub mod utilities;
+pub mod weld;
+pub mod wythoff;
+
+// Rendering modules.
+#[cfg(feature = "rendering")]
+pub mod program;
+#[cfg(feature = "rendering")]
+pub mod renderer;
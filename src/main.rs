@@ -5,32 +5,29 @@
 #![allow(unused_assignments)]
 #![allow(unreachable_code)]
 #![allow(unreachable_patterns)]
-mod gl { include!("../target/debug/build/gl-aa90ab3ecc411c34/out/bindings.rs"); }
-
 extern crate cgmath;
+extern crate four;
+extern crate gl;
 extern crate glutin;
-extern crate image;
 
 // Module imports.
-mod camera;
-mod constants;
-mod hyperplane;
 mod interaction;
-mod math;
-mod mesh;
-mod polychora;
-mod program;
-mod tetrahedron;
-mod utilities;
 
 // Struct and function imports.
-use camera::{Camera, FourCamera, ThreeCamera};
-use hyperplane::Hyperplane;
+use four::camera::{Camera, FourCamera, ThreeCamera, View};
+use four::export::{CrossSection, Format};
+use four::hyperplane::Hyperplane;
+use four::mesh::Mesh;
+use four::operators;
+use four::polychora::Polychoron;
+use four::program::Program;
+use four::renderer::Renderer;
+use four::shape_file::ShapeFile;
+use four::stereographic::StereographicProjection;
+use four::{constants, math, utilities};
 use interaction::InteractionState;
-use mesh::Mesh;
-use polychora::Polychoron;
-use program::Program;
 
+use std::env;
 use std::path::Path;
 use std::time::{Duration, SystemTime};
 
@@ -68,6 +65,25 @@ fn set_draw_state() {
     }
 }
 
+/// Sets the 4D -> 3D projection uniforms for drawing points that have already been
+/// projected to 3D on the CPU (i.e. Schlegel diagrams), bypassing the 4D camera.
+fn set_projected_uniforms(program: &Program) {
+    program.uniform_bool("u_perspective_4D", false);
+    program.uniform_4f("u_four_from", &Vector4::zero());
+    program.uniform_matrix_4f("u_four_model", &Matrix4::identity());
+    program.uniform_matrix_4f("u_four_view", &Matrix4::identity());
+}
+
+/// Builds the camera that is used to perform the 4D -> 3D perspective projection.
+fn build_four_camera() -> FourCamera {
+    FourCamera::new(
+        Vector4::unit_x() * 1.5,
+        Vector4::zero(),
+        Vector4::unit_y(),
+        Vector4::unit_z(),
+    )
+}
+
 fn main() {
     // Set up windowing and event loop.
     let mut events_loop = glutin::EventsLoop::new();
@@ -85,34 +101,66 @@ fn main() {
     // Set up the slicing hyperplane.
     let mut hyperplane = Hyperplane::new(Vector4::unit_w(), 0.1);
 
-    // Load several polychora and compute their tetrahedral decompositions.
-    let mut meshes = vec![
-        Mesh::new(Polychoron::Cell8),
-        Mesh::new(Polychoron::Cell16),
-        Mesh::new(Polychoron::Cell24),
-        Mesh::new(Polychoron::Cell120),
-        Mesh::new(Polychoron::Cell600),
-    ];
-
-    // Set up the model matrices, in 3-space.
-    let mut model_matrices = vec![
-        Matrix4::from_translation(Vector3::unit_x() * -4.5),
-        Matrix4::from_translation(Vector3::unit_x() * -2.0),
-        Matrix4::from_translation(Vector3::unit_x() * 0.0),
-        Matrix4::from_translation(Vector3::unit_x() * 2.0),
-        Matrix4::from_translation(Vector3::unit_x() * 4.5),
-    ];
+    // Load several polychora and compute their tetrahedral decompositions. Any shape files
+    // passed on the command line are shown instead of the built-in polychora.
+    let shapes = env::args()
+        .skip(1)
+        .filter_map(|path| match ShapeFile::load(Path::new(&path)) {
+            Ok(shape) => {
+                // Shape files may contain mistakes, so report any problems with their
+                // topology (the mesh is still drawn).
+                let mesh = Mesh::new(shape);
+                if let Err(errors) = mesh.validate() {
+                    for error in errors {
+                        eprintln!("Invalid topology in shape file `{}`: {}", path, error);
+                    }
+                }
+                Some(Renderer::new(mesh))
+            }
+            Err(error) => {
+                eprintln!("Failed to load shape file `{}`: {}", path, error);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let (mut renderers, mut model_matrices) = if shapes.is_empty() {
+        // Fall back to the built-in polychora if no shape files were loaded.
+        let renderers = vec![
+            Renderer::new(Mesh::new(Polychoron::Cell5)),
+            Renderer::new(Mesh::new(Polychoron::Cell8)),
+            Renderer::new(Mesh::new(Polychoron::Cell16)),
+            Renderer::new(Mesh::new(Polychoron::Cell24)),
+            Renderer::new(Mesh::new(Polychoron::Cell120)),
+            Renderer::new(Mesh::new(Polychoron::Cell600)),
+        ];
+
+        // Set up the model matrices, in 3-space.
+        let model_matrices = vec![
+            Matrix4::from_translation(Vector3::unit_x() * -5.5),
+            Matrix4::from_translation(Vector3::unit_x() * -3.5),
+            Matrix4::from_translation(Vector3::unit_x() * -1.25),
+            Matrix4::from_translation(Vector3::unit_x() * 1.25),
+            Matrix4::from_translation(Vector3::unit_x() * 3.5),
+            Matrix4::from_translation(Vector3::unit_x() * 5.5),
+        ];
+
+        (renderers, model_matrices)
+    } else {
+        // Space the loaded shapes out evenly along the x-axis.
+        let center = (shapes.len() - 1) as f32 * 0.5;
+        let model_matrices = (0..shapes.len())
+            .map(|i| Matrix4::from_translation(Vector3::unit_x() * (i as f32 - center) * 2.5))
+            .collect::<Vec<_>>();
+
+        (shapes, model_matrices)
+    };
 
     // Set up the "model" matrix, in 4-space.
     let mut rotation_in_4d = Matrix4::identity();
 
     // Initialize the camera that will be used to perform the 4D -> 3D projection.
-    let four_cam = FourCamera::new(
-        Vector4::unit_x() * 1.5,
-        Vector4::zero(),
-        Vector4::unit_y(),
-        Vector4::unit_z(),
-    );
+    let mut four_cam = build_four_camera();
 
     // Initialize the camera that will be used to perform the 3D -> 2D projection.
     let mut three_cam = ThreeCamera::new(
@@ -132,177 +180,413 @@ fn main() {
     let mut interaction = InteractionState::new();
     let mut mode = 0;
 
+    // The coordinate axis that the 4D camera was last moved onto (see `g` below).
+    let mut four_cam_axis = 0;
+
+    // Whether each of the first 4 draw modes uses a perspective (`true`) or orthographic
+    // (`false`) 4D -> 3D projection. The Schlegel diagram and stereographic modes are
+    // already projections from 4D -> 3D, so they aren't affected.
+    let mut perspective_4d = [false, true, true, false];
+    let mut wireframe = false;
+    let mut slice_cells = false;
+
+    // The net of each polychoron is animated between folded (`0`) and unfolded (`1`).
+    let mut unfold_amount = 0.0;
+    let mut unfold_target = 1.0;
+
+    // The cell that each Schlegel diagram is projected through, and how much the other cells
+    // are shrunk (`1` leaves them as they are).
+    let mut schlegel_cell: u32 = 0;
+    let mut schlegel_shrink: f32 = 1.0;
+
     // Set up timing information (can be used inside of the shaders to animate objects).
     let start = SystemTime::now();
     let mut frame_count = 0;
+    let mut last_milliseconds = 0.0;
 
     loop {
         frame_count += 1;
 
-        events_loop.poll_events(|event| match event {
-            glutin::Event::WindowEvent { event, .. } => match event {
-                glutin::WindowEvent::Closed => (),
-                glutin::WindowEvent::MouseMoved { position, .. } => {
-                    // Store the normalized mouse position.
-                    interaction.cursor_prev = interaction.cursor_curr;
-                    interaction.cursor_curr.x = position.0 as f32 / constants::WIDTH as f32;
-                    interaction.cursor_curr.y = position.1 as f32 / constants::HEIGHT as f32;
-
-                    if interaction.lmouse_pressed {
-                        let delta = interaction.get_mouse_delta() * constants::MOUSE_SENSITIVITY;
-
-                        if interaction.shift_pressed {
-                            let rot = true;
-
-                            let rot_xw = math::get_simple_rotation_matrix(math::Plane::XW, delta.x);
-                            let rot_yw = math::get_simple_rotation_matrix(math::Plane::YW, delta.y);
-                            rotation_in_4d = rot_xw * rot_yw * rotation_in_4d;
-                        } else if interaction.ctrl_pressed {
-                            let rot_zw = math::get_simple_rotation_matrix(math::Plane::ZW, delta.x);
-                            let rot_zx = math::get_simple_rotation_matrix(math::Plane::ZX, delta.y);
-                            rotation_in_4d = rot_zw * rot_zx * rotation_in_4d;
-                        } else {
-                            let rot_xz = Matrix4::from_angle_y(cgmath::Rad(delta.x));
-                            let rot_yz = Matrix4::from_angle_x(cgmath::Rad(delta.y));
-
-                            for model in model_matrices.iter_mut() {
-                                *model = rot_yz * *model;
+        events_loop.poll_events(|event| {
+            if let glutin::Event::WindowEvent { event, .. } = event {
+                match event {
+                    glutin::WindowEvent::Closed => (),
+                    glutin::WindowEvent::MouseMoved { position, .. } => {
+                        // Store the normalized mouse position.
+                        interaction.cursor_prev = interaction.cursor_curr;
+                        interaction.cursor_curr.x = position.0 as f32 / constants::WIDTH as f32;
+                        interaction.cursor_curr.y = position.1 as f32 / constants::HEIGHT as f32;
+
+                        if interaction.lmouse_pressed {
+                            let delta =
+                                interaction.get_mouse_delta() * constants::MOUSE_SENSITIVITY;
+
+                            if interaction.alt_pressed {
+                                // Orbit the 4D camera around its target.
+                                four_cam.orbit_in_plane(math::Plane::XW, delta.x);
+                                four_cam.orbit_in_plane(math::Plane::YW, delta.y);
+                            } else if interaction.shift_pressed {
+                                let rot = true;
+
+                                let rot_xw =
+                                    math::get_simple_rotation_matrix(math::Plane::XW, delta.x);
+                                let rot_yw =
+                                    math::get_simple_rotation_matrix(math::Plane::YW, delta.y);
+                                rotation_in_4d = rot_xw * rot_yw * rotation_in_4d;
+                            } else if interaction.ctrl_pressed {
+                                let rot_zw =
+                                    math::get_simple_rotation_matrix(math::Plane::ZW, delta.x);
+                                let rot_zx =
+                                    math::get_simple_rotation_matrix(math::Plane::ZX, delta.y);
+                                rotation_in_4d = rot_zw * rot_zx * rotation_in_4d;
+                            } else {
+                                let rot_xz = Matrix4::from_angle_y(cgmath::Rad(delta.x));
+                                let rot_yz = Matrix4::from_angle_x(cgmath::Rad(delta.y));
+
+                                for model in model_matrices.iter_mut() {
+                                    *model = rot_yz * *model;
+                                }
                             }
                         }
                     }
-                }
-                glutin::WindowEvent::MouseInput { state, button, .. } => match button {
-                    glutin::MouseButton::Left => {
-                        if let glutin::ElementState::Pressed = state {
-                            interaction.cursor_pressed = interaction.cursor_curr;
-                            interaction.lmouse_pressed = true;
-                        } else {
-                            interaction.lmouse_pressed = false;
+                    glutin::WindowEvent::MouseInput { state, button, .. } => match button {
+                        glutin::MouseButton::Left => {
+                            if let glutin::ElementState::Pressed = state {
+                                interaction.cursor_pressed = interaction.cursor_curr;
+                                interaction.lmouse_pressed = true;
+                            } else {
+                                interaction.lmouse_pressed = false;
+                            }
                         }
-                    }
-                    glutin::MouseButton::Right => {
-                        if let glutin::ElementState::Pressed = state {
-                            interaction.rmouse_pressed = true;
-                        } else {
-                            interaction.rmouse_pressed = false;
+                        glutin::MouseButton::Right => {
+                            if let glutin::ElementState::Pressed = state {
+                                interaction.rmouse_pressed = true;
+                            } else {
+                                interaction.rmouse_pressed = false;
+                            }
                         }
-                    }
-                    _ => (),
-                },
-                glutin::WindowEvent::KeyboardInput { input, .. } => {
-                    if let Some(key) = input.virtual_keycode {
-                        match input.state {
-                            glutin::ElementState::Pressed => match key {
-                                glutin::VirtualKeyCode::S => {
-                                    let file_name = format!("frame_{}.png", frame_count);
-                                    let path = Path::new(&file_name);
-                                    utilities::save_frame(
-                                        path,
-                                        constants::WIDTH,
-                                        constants::HEIGHT,
-                                    );
-                                }
-                                glutin::VirtualKeyCode::LShift => {
-                                    interaction.shift_pressed = true;
-                                }
-                                glutin::VirtualKeyCode::LControl => {
-                                    interaction.ctrl_pressed = true;
-                                }
-                                glutin::VirtualKeyCode::T => {
-                                    mode += 1;
-                                    mode = mode % 3;
-                                }
-                                glutin::VirtualKeyCode::W => unsafe {
-                                    gl::PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+                        _ => (),
+                    },
+                    glutin::WindowEvent::KeyboardInput { input, .. } => {
+                        if let Some(key) = input.virtual_keycode {
+                            match input.state {
+                                glutin::ElementState::Pressed => match key {
+                                    glutin::VirtualKeyCode::S => {
+                                        let file_name = format!("frame_{}.png", frame_count);
+                                        let path = Path::new(&file_name);
+                                        utilities::save_frame(
+                                            path,
+                                            constants::WIDTH,
+                                            constants::HEIGHT,
+                                        );
+                                    }
+                                    glutin::VirtualKeyCode::E => {
+                                        // Export the current cross-section of each mesh:
+                                        // hold `shift` for PLY or `ctrl` for STL.
+                                        let format = if interaction.shift_pressed {
+                                            Format::Ply
+                                        } else if interaction.ctrl_pressed {
+                                            Format::Stl
+                                        } else {
+                                            Format::Obj
+                                        };
+
+                                        for (i, renderer) in renderers.iter().enumerate() {
+                                            let file_name = format!(
+                                                "slice_{}_{}.{}",
+                                                frame_count,
+                                                i,
+                                                format.get_extension()
+                                            );
+                                            CrossSection::new(renderer.get_mesh(), &hyperplane)
+                                                .save(Path::new(&file_name))
+                                                .unwrap_or_else(|error| {
+                                                    eprintln!(
+                                                        "Failed to export `{}`: {}",
+                                                        file_name, error
+                                                    )
+                                                });
+                                        }
+                                    }
+                                    glutin::VirtualKeyCode::LShift => {
+                                        interaction.shift_pressed = true;
+                                    }
+                                    glutin::VirtualKeyCode::LControl => {
+                                        interaction.ctrl_pressed = true;
+                                    }
+                                    glutin::VirtualKeyCode::LAlt => {
+                                        interaction.alt_pressed = true;
+                                    }
+                                    glutin::VirtualKeyCode::LBracket => {
+                                        let fov = four_cam.get_fov();
+                                        four_cam.set_fov(fov - constants::FOV_INCREMENT);
+                                    }
+                                    glutin::VirtualKeyCode::RBracket => {
+                                        let fov = four_cam.get_fov();
+                                        four_cam.set_fov(fov + constants::FOV_INCREMENT);
+                                    }
+                                    glutin::VirtualKeyCode::G => {
+                                        // Move the 4D camera onto the next coordinate axis.
+                                        four_cam_axis = (four_cam_axis + 1) % 4;
+                                        four_cam.look_along_axis(four_cam_axis);
+                                    }
+                                    glutin::VirtualKeyCode::T => {
+                                        mode += 1;
+                                        mode %= 6;
+                                    }
+                                    glutin::VirtualKeyCode::D => {
+                                        // Replace each mesh with its (geometric) dual.
+                                        for renderer in renderers.iter_mut() {
+                                            if let Some(dual) = renderer.get_mesh().get_dual() {
+                                                *renderer = Renderer::new(dual);
+                                            }
+                                        }
+                                    }
+                                    glutin::VirtualKeyCode::R
+                                    | glutin::VirtualKeyCode::U
+                                    | glutin::VirtualKeyCode::B
+                                    | glutin::VirtualKeyCode::N
+                                    | glutin::VirtualKeyCode::O => {
+                                        // Apply one of the polytope operators to each mesh:
+                                        // meshes that it doesn't apply to are left alone.
+                                        let operator: fn(&Mesh) -> Option<Mesh> = match key {
+                                            glutin::VirtualKeyCode::R => operators::rectify,
+                                            glutin::VirtualKeyCode::U => operators::truncate,
+                                            glutin::VirtualKeyCode::B => operators::bitruncate,
+                                            glutin::VirtualKeyCode::N => operators::snub,
+                                            _ => operators::compound_with_dual,
+                                        };
+
+                                        for renderer in renderers.iter_mut() {
+                                            if let Some(mesh) = operator(renderer.get_mesh()) {
+                                                *renderer = Renderer::new(mesh);
+                                            }
+                                        }
+                                    }
+                                    glutin::VirtualKeyCode::P => {
+                                        if let Some(perspective) = perspective_4d.get_mut(mode) {
+                                            *perspective = !*perspective;
+                                        }
+                                    }
+                                    glutin::VirtualKeyCode::V => {
+                                        let orthographic = !three_cam.is_orthographic();
+                                        three_cam.set_orthographic(orthographic);
+                                    }
+                                    glutin::VirtualKeyCode::Key1 => {
+                                        three_cam.set_view(View::Front);
+                                    }
+                                    glutin::VirtualKeyCode::Key2 => {
+                                        three_cam.set_view(View::Top);
+                                    }
+                                    glutin::VirtualKeyCode::Key3 => {
+                                        three_cam.set_view(View::Side);
+                                    }
+                                    glutin::VirtualKeyCode::Space => {
+                                        unfold_target = 1.0 - unfold_target;
+                                    }
+                                    glutin::VirtualKeyCode::Right => {
+                                        schlegel_cell += 1;
+                                    }
+                                    glutin::VirtualKeyCode::Left => {
+                                        schlegel_cell = schlegel_cell.saturating_sub(1);
+                                    }
+                                    glutin::VirtualKeyCode::Up => {
+                                        schlegel_shrink = (schlegel_shrink + 0.1).min(1.0);
+                                    }
+                                    glutin::VirtualKeyCode::Down => {
+                                        schlegel_shrink = (schlegel_shrink - 0.1).max(0.1);
+                                    }
+                                    glutin::VirtualKeyCode::C => {
+                                        slice_cells = !slice_cells;
+                                    }
+                                    glutin::VirtualKeyCode::W => unsafe {
+                                        wireframe = true;
+                                        gl::PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+                                    },
+                                    glutin::VirtualKeyCode::F => unsafe {
+                                        wireframe = false;
+                                        gl::PolygonMode(gl::FRONT_AND_BACK, gl::FILL);
+                                    },
+                                    glutin::VirtualKeyCode::H => {
+                                        rotation_in_4d = Matrix4::identity();
+                                        four_cam = build_four_camera();
+                                        four_cam_axis = 0;
+                                    }
+                                    _ => (),
                                 },
-                                glutin::VirtualKeyCode::F => unsafe {
-                                    gl::PolygonMode(gl::FRONT_AND_BACK, gl::FILL);
+                                glutin::ElementState::Released => match key {
+                                    glutin::VirtualKeyCode::LShift => {
+                                        interaction.shift_pressed = false;
+                                    }
+                                    glutin::VirtualKeyCode::LControl => {
+                                        interaction.ctrl_pressed = false;
+                                    }
+                                    glutin::VirtualKeyCode::LAlt => {
+                                        interaction.alt_pressed = false;
+                                    }
+                                    _ => (),
                                 },
-                                glutin::VirtualKeyCode::H => {
-                                    rotation_in_4d = Matrix4::identity();
-                                }
-                                _ => (),
-                            },
-                            glutin::ElementState::Released => match key {
-                                glutin::VirtualKeyCode::LShift => {
-                                    interaction.shift_pressed = false;
-                                }
-                                glutin::VirtualKeyCode::LControl => {
-                                    interaction.ctrl_pressed = false;
-                                }
-                                _ => (),
-                            },
+                            }
                         }
                     }
-                }
-                glutin::WindowEvent::MouseWheel { delta, .. } => {
-                    if let glutin::MouseScrollDelta::LineDelta(_, line_y) = delta {
-                        let mut current_from = three_cam.get_from();
+                    glutin::WindowEvent::MouseWheel {
+                        delta: glutin::MouseScrollDelta::LineDelta(_, line_y),
+                        ..
+                    } => {
+                        if interaction.alt_pressed {
+                            // Move the 4D camera towards or away from its target.
+                            let mut distance = four_cam.get_distance();
+
+                            if line_y == 1.0 {
+                                distance -= constants::ZOOM_INCREMENT;
+                            } else {
+                                distance += constants::ZOOM_INCREMENT;
+                            }
 
-                        if line_y == 1.0 {
-                            current_from.z -= constants::ZOOM_INCREMENT;
+                            four_cam.set_distance(distance);
+                        } else if line_y == 1.0 {
+                            three_cam.zoom(constants::ZOOM_INCREMENT);
                         } else {
-                            current_from.z += constants::ZOOM_INCREMENT;
+                            three_cam.zoom(-constants::ZOOM_INCREMENT);
                         }
-
-                        three_cam.set_from(&current_from);
                     }
+                    _ => (),
                 }
-                _ => (),
-            },
-            _ => (),
+            }
         });
 
         // Retrieve the number of milliseconds since application launch.
         let elapsed = start.elapsed().unwrap();
         let seconds = elapsed.as_secs() * 1000 + elapsed.subsec_nanos() as u64 / 1_000_000;
         let milliseconds = (seconds as f32) / 1000.0;
+        let delta_time = milliseconds - last_milliseconds;
+        last_milliseconds = milliseconds;
         clear();
 
         projections_program.uniform_1f("u_time", milliseconds);
 
-        // Uniforms for 4D -> 3D projection.
-        projections_program.uniform_4f("u_four_from", &four_cam.from);
-        projections_program.uniform_matrix_4f("u_four_model", &rotation_in_4d);
-        projections_program.uniform_matrix_4f("u_four_view", &four_cam.look_at);
-        projections_program.uniform_matrix_4f("u_four_projection", &four_cam.projection);
+        // Uniforms for 4D -> 3D projection. The 4D model matrix is set per mesh (see below).
+        // The orthographic projection bypasses the 4D camera, so that it simply drops the
+        // w-coordinate and lines up with the slices (which are taken along the w-axis).
+        let perspective = perspective_4d.get(mode).cloned().unwrap_or(false);
+        if perspective {
+            projections_program.uniform_4f("u_four_from", &four_cam.get_from());
+            projections_program.uniform_matrix_4f("u_four_view", four_cam.get_look_at());
+        } else {
+            projections_program.uniform_4f("u_four_from", &Vector4::zero());
+            projections_program.uniform_matrix_4f("u_four_view", &Matrix4::identity());
+        }
+        projections_program.uniform_matrix_4f("u_four_projection", four_cam.get_projection());
+        projections_program.uniform_bool("u_perspective_4D", perspective);
+        projections_program.uniform_bool("u_shade_by_position", mode == 1 || mode == 2);
+
+        // Every draw mode uses the same 4D model matrix for each mesh: its transform.
+        for renderer in renderers.iter_mut() {
+            renderer.get_mesh_mut().set_transform(&rotation_in_4d);
+        }
 
         // Uniforms for 3D -> 2D projection.
-        projections_program.uniform_matrix_4f("u_three_view", &three_cam.get_look_at());
-        projections_program.uniform_matrix_4f("u_three_projection", &three_cam.get_projection());
+        projections_program.uniform_matrix_4f("u_three_view", three_cam.get_look_at());
+        projections_program.uniform_matrix_4f("u_three_projection", three_cam.get_projection());
 
         match mode {
             0 => {
-                // First, slice each mesh.
-                for mesh in meshes.iter_mut() {
-                    mesh.set_transform(&rotation_in_4d);
-                    mesh.slice(&hyperplane);
+                // First, slice each mesh: either per-tetrahedron on the GPU, or per-cell
+                // on the CPU.
+                for renderer in renderers.iter_mut() {
+                    if slice_cells {
+                        renderer.slice_cells(&hyperplane);
+                    } else {
+                        renderer.slice(&hyperplane);
+                    }
                 }
 
                 projections_program.bind();
-                projections_program.uniform_bool("u_perspective_4D", false);
-                // (0) Draw the results of the slicing operations.
-                for (i, mesh) in meshes.iter().enumerate() {
+                // (0) Draw the results of the slicing operations. The mesh's transform was
+                // already applied while slicing, so the model matrix is the identity here.
+                projections_program.uniform_matrix_4f("u_four_model", &Matrix4::identity());
+                for (i, renderer) in renderers.iter().enumerate() {
                     projections_program.uniform_matrix_4f("u_three_model", &model_matrices[i]);
-                    mesh.draw_slice();
+                    if slice_cells {
+                        renderer.draw_cell_slices(wireframe);
+                    } else {
+                        renderer.draw_slice();
+                    }
                 }
             }
             1 => {
                 projections_program.bind();
-                projections_program.uniform_bool("u_perspective_4D", true);
                 // (1) Draw the wireframes of all of the tetrahedra that make up the polychora.
-                for (i, mesh) in meshes.iter().enumerate() {
+                for (i, renderer) in renderers.iter().enumerate() {
+                    projections_program
+                        .uniform_matrix_4f("u_four_model", renderer.get_mesh().get_transform());
                     projections_program.uniform_matrix_4f("u_three_model", &model_matrices[i]);
-                    mesh.draw_tetrahedra();
+                    renderer.draw_tetrahedra();
                 }
             }
             2 => {
                 projections_program.bind();
-                projections_program.uniform_bool("u_perspective_4D", true);
                 // (2) Draw the skeletons (wireframes) of the polychora.
-                for (i, mesh) in meshes.iter().enumerate() {
+                for (i, renderer) in renderers.iter().enumerate() {
+                    projections_program
+                        .uniform_matrix_4f("u_four_model", renderer.get_mesh().get_transform());
+                    projections_program.uniform_matrix_4f("u_three_model", &model_matrices[i]);
+                    renderer.draw_edges();
+                }
+            }
+            3 => {
+                // Move the nets towards being folded or unfolded, easing in and out.
+                let step = constants::UNFOLD_SPEED * delta_time;
+                if unfold_amount < unfold_target {
+                    unfold_amount = (unfold_amount + step).min(unfold_target);
+                } else {
+                    unfold_amount = (unfold_amount - step).max(unfold_target);
+                }
+                let eased = unfold_amount * unfold_amount * (3.0 - 2.0 * unfold_amount);
+
+                projections_program.bind();
+                // (3) Draw the (partially) unfolded nets of the polychora: these are drawn at
+                // half-size, so that neighboring nets don't overlap. The nets are expressed
+                // relative to one of their cells, so the model matrix is the identity.
+                projections_program.uniform_matrix_4f("u_four_model", &Matrix4::identity());
+                for (i, renderer) in renderers.iter_mut().enumerate() {
+                    renderer.unfold(eased);
+                    projections_program.uniform_matrix_4f(
+                        "u_three_model",
+                        &(model_matrices[i] * Matrix4::from_scale(0.5)),
+                    );
+                    renderer.draw_cells();
+                }
+            }
+            4 => {
+                projections_program.bind();
+                set_projected_uniforms(&projections_program);
+                // (4) Draw the Schlegel diagrams of the polychora, each of which is projected
+                // through one of its cells (the outer hull).
+                for (i, renderer) in renderers.iter_mut().enumerate() {
+                    let number_of_cells = renderer.get_mesh().get_cell_faces().len() as u32;
+                    if number_of_cells > 0 {
+                        renderer.project_schlegel(schlegel_cell % number_of_cells, schlegel_shrink);
+                    }
+                    projections_program.uniform_matrix_4f("u_three_model", &model_matrices[i]);
+                    renderer.draw_cells();
+                }
+            }
+            5 => {
+                // The pole of the projection is placed wherever the 4D camera is looking from.
+                let projection = StereographicProjection::from_camera(
+                    &four_cam,
+                    constants::STEREOGRAPHIC_SEGMENTS,
+                );
+
+                projections_program.bind();
+                set_projected_uniforms(&projections_program);
+                // (5) Draw the stereographic projections of the polychora, whose edges become
+                // arcs of circles.
+                for (i, renderer) in renderers.iter_mut().enumerate() {
+                    renderer.project_stereographic(&projection);
                     projections_program.uniform_matrix_4f("u_three_model", &model_matrices[i]);
-                    mesh.draw_edges();
+                    renderer.draw_curves();
                 }
             }
             _ => (),
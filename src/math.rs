@@ -1,4 +1,4 @@
-use cgmath::{self, InnerSpace, Matrix4, Vector3, Vector4, Zero};
+use cgmath::{BaseFloat, InnerSpace, Matrix4, Vector3, Vector4, Zero};
 
 use hyperplane::Hyperplane;
 use utilities;
@@ -43,13 +43,12 @@ pub fn cross(u: &Vector4<f32>, v: &Vector4<f32>, w: &Vector4<f32>) -> Vector4<f3
     let e = (v[1] * w[3]) - (v[3] * w[1]);
     let f = (v[2] * w[3]) - (v[3] * w[2]);
 
-    let result = Vector4::new(
+    Vector4::new(
         (u[1] * f) - (u[2] * e) + (u[3] * d),
         -(u[0] * f) + (u[2] * c) - (u[3] * b),
         (u[0] * e) - (u[1] * c) + (u[3] * a),
         -(u[0] * d) + (u[1] * b) - (u[2] * a),
-    );
-    result
+    )
 }
 
 /// 4-dimensional rotations are best thought about as rotations parallel to a plane.
@@ -156,8 +155,35 @@ pub fn get_double_rotation_matrix(first_plane: Plane, alpha: f32, beta: f32) ->
     }
 }
 
+/// Returns a matrix that rotates by `angle` in the plane spanned by the orthonormal
+/// vectors `a` and `b` (so that `a` rotates towards `b`). Unlike the matrices returned by
+/// `get_simple_rotation_matrix(...)`, the plane of rotation doesn't have to be aligned with
+/// any of the coordinate axes. Everything orthogonal to the plane is left unchanged.
+pub fn get_plane_rotation_matrix<S: BaseFloat>(
+    a: &Vector4<S>,
+    b: &Vector4<S>,
+    angle: S,
+) -> Matrix4<S> {
+    let c = angle.cos();
+    let s = angle.sin();
+
+    // Each column is the image of one of the coordinate axes, `e`:
+    // `e + (c - 1)(a(a·e) + b(b·e)) + s(b(a·e) - a(b·e))`.
+    let mut columns = [Vector4::zero(); 4];
+    for (axis, column) in columns.iter_mut().enumerate() {
+        let mut e = Vector4::zero();
+        e[axis] = S::one();
+
+        let (ae, be) = (a.dot(e), b.dot(e));
+        *column = e + (a * ae + b * be) * (c - S::one()) + (b * ae - a * be) * s;
+    }
+
+    Matrix4::from_cols(columns[0], columns[1], columns[2], columns[3])
+}
+
 /// See the notes above in `get_double_rotation_matrix(...)`. This function is
 /// mostly here for completeness.
+#[allow(dead_code)]
 fn get_isoclinic_rotation_matrix(first_plane: Plane, alpha_beta: f32) -> Matrix4<f32> {
     get_double_rotation_matrix(first_plane, alpha_beta, alpha_beta)
 }
@@ -169,10 +195,7 @@ fn get_isoclinic_rotation_matrix(first_plane: Plane, alpha_beta: f32) -> Matrix4
 /// in a "clockwise" or "counter-clockwise" order.
 ///
 /// Reference: `https://math.stackexchange.com/questions/978642/how-to-sort-vertices-of-a-polygon-in-counter-clockwise-order`
-pub fn sort_points_on_plane(
-    points: &Vec<Vector4<f32>>,
-    hyperplane: &Hyperplane,
-) -> Vec<Vector4<f32>> {
+pub fn sort_points_on_plane(points: &[Vector4<f32>], hyperplane: &Hyperplane) -> Vec<Vector4<f32>> {
     let largest_index = utilities::index_of_largest(&hyperplane.normal);
 
     // First, project the 4D points to 3D. We do this by dropping the coordinate
@@ -215,7 +238,7 @@ pub fn sort_points_on_plane(
 
     // Add the first point `a`.
     indices.push((0, 0.0));
-    indices.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    indices.sort_by(|a, b| a.1.total_cmp(&b.1));
 
     // Now, return the original set of 4D points in the proper order.
     let points_sorted = indices
@@ -226,6 +249,62 @@ pub fn sort_points_on_plane(
     points_sorted
 }
 
+/// Given a set of points embedded in 4-dimensions that all lie in a common 2-dimensional
+/// plane (for example, the vertices of a convex polygon), returns the indices of the points
+/// sorted by their angle around the polygon's centroid. Unlike `sort_points_on_plane(...)`,
+/// this does not require the 3-dimensional hyperplane that the polygon lives in.
+pub fn sort_indices_on_plane(points: &[Vector4<f32>]) -> Vec<usize> {
+    let centroid = utilities::average(points, &Vector4::zero());
+
+    // Build an orthonormal basis for the plane of the polygon: the first axis points
+    // towards the first vertex, and the second is whichever direction is "most"
+    // orthogonal to it.
+    let u = (points[0] - centroid).normalize();
+    let v = points
+        .iter()
+        .map(|point| {
+            let d = point - centroid;
+            d - u * u.dot(d)
+        })
+        .max_by(|a, b| a.magnitude2().total_cmp(&b.magnitude2()))
+        .unwrap()
+        .normalize();
+
+    let mut indices = points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            let d = point - centroid;
+            (index, d.dot(v).atan2(d.dot(u)))
+        })
+        .collect::<Vec<_>>();
+    indices.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    indices.iter().map(|(index, _)| *index).collect()
+}
+
+/// Returns an orthonormal basis for the 3-dimensional subspace orthogonal to `normal`,
+/// which can be used to express points that lie in a hyperplane in 3D coordinates. The
+/// basis is built by orthogonalizing the x, y, z, and w-axes (in that order) against the
+/// normal, so for a hyperplane with normal `<0, 0, 0, 1>`, it is simply the x, y, and z-axes.
+pub fn get_hyperplane_basis(normal: &Vector4<f32>) -> [Vector4<f32>; 3] {
+    let mut basis: Vec<Vector4<f32>> = vec![normal.normalize()];
+
+    for axis in 0..4 {
+        let mut direction = Vector4::zero();
+        direction[axis] = 1.0;
+        for existing in basis.iter() {
+            direction -= existing * existing.dot(direction);
+        }
+
+        if basis.len() < 4 && direction.magnitude() > 1.0e-3 {
+            basis.push(direction.normalize());
+        }
+    }
+
+    [basis[1], basis[2], basis[3]]
+}
+
 /// Construct a 4x4 matrix representing a series of plane rotations that cause
 /// the vector <1, 1, 1, 1> to align with the x-axis, <1, 0, 0, 0>. This is useful
 /// for projecting points from 4D -> 3D, if we decide to slice corner-first (which
@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use cgmath::{InnerSpace, Vector3, Vector4, Zero};
+
+use hyperplane::Hyperplane;
+use math;
+use mesh::Mesh;
+use weld::SlicePolyhedron;
+
+/// The contribution of a single cell to the surface of a cross-section.
+#[derive(Copy, Clone, Debug)]
+pub struct CellMeasurement {
+    /// The index of the cell that was sliced
+    pub cell_index: u32,
+
+    /// The area of the cell's (polygonal) slice
+    pub area: f32,
+
+    /// The centroid of the cell's (polygonal) slice
+    pub centroid: Vector4<f32>,
+}
+
+/// Geometric measurements of the 3-dimensional cross-section of a mesh.
+#[derive(Clone, Debug)]
+pub struct SliceMeasurements {
+    /// The (3-dimensional) volume enclosed by the cross-section
+    pub volume: f32,
+
+    /// The surface area of the cross-section, which is the sum of the areas of all of the
+    /// cell slices
+    pub surface_area: f32,
+
+    /// The centroid of the solid enclosed by the cross-section, which lies in the slicing
+    /// hyperplane
+    pub centroid: Vector4<f32>,
+
+    /// The contribution of each cell that was cut by the hyperplane, sorted by cell index
+    pub cells: Vec<CellMeasurement>,
+}
+
+impl SliceMeasurements {
+    /// Measures a cross-section, given the closed polyhedra that make it up (see
+    /// `Mesh::slice_polyhedra(...)`) and the `hyperplane` that produced them.
+    ///
+    /// The volume and centroid are computed by connecting each (triangulated) face to the
+    /// origin of the hyperplane and summing the signed volumes of the resulting tetrahedra.
+    /// This relies on the faces being consistently oriented. Note that each connected
+    /// polyhedron is treated as a separate solid, so cavities (polyhedra nested inside of
+    /// others) are counted as solid.
+    pub fn new(polyhedra: &[SlicePolyhedron], hyperplane: &Hyperplane) -> SliceMeasurements {
+        // Points are measured in 3D coordinates within the hyperplane.
+        let normal = hyperplane.normal.normalize();
+        let basis = math::get_hyperplane_basis(&normal);
+        let offset = -hyperplane.displacement / hyperplane.normal.magnitude();
+        let to_3d = |point: &Vector4<f32>| {
+            Vector3::new(
+                point.dot(basis[0]),
+                point.dot(basis[1]),
+                point.dot(basis[2]),
+            )
+        };
+        let to_4d = |point: &Vector3<f32>| {
+            basis[0] * point.x + basis[1] * point.y + basis[2] * point.z + normal * offset
+        };
+
+        let mut volume = 0.0;
+        let mut moment = Vector3::zero();
+        let mut surface_area = 0.0;
+        let mut cells: BTreeMap<u32, (f32, Vector3<f32>)> = BTreeMap::new();
+
+        for polyhedron in polyhedra.iter() {
+            let positions = polyhedron
+                .get_vertices()
+                .iter()
+                .map(&to_3d)
+                .collect::<Vec<_>>();
+
+            for (face, cell_index) in polyhedron
+                .get_faces()
+                .iter()
+                .zip(polyhedron.get_cell_indices().iter())
+            {
+                let cell = cells.entry(*cell_index).or_insert((0.0, Vector3::zero()));
+
+                // Fan out each face into triangles.
+                let a = positions[face[0] as usize];
+                for k in 1..face.len() - 1 {
+                    let b = positions[face[k] as usize];
+                    let c = positions[face[k + 1] as usize];
+
+                    let signed_volume = a.dot(b.cross(c)) / 6.0;
+                    volume += signed_volume;
+                    moment += (a + b + c) * (signed_volume / 4.0);
+
+                    let area = (b - a).cross(c - a).magnitude() * 0.5;
+                    surface_area += area;
+                    cell.0 += area;
+                    cell.1 += (a + b + c) * (area / 3.0);
+                }
+            }
+        }
+
+        let centroid = if volume.abs() > 0.0 {
+            to_4d(&(moment / volume))
+        } else {
+            to_4d(&Vector3::zero())
+        };
+
+        SliceMeasurements {
+            volume,
+            surface_area,
+            centroid,
+            cells: cells
+                .iter()
+                .filter(|(_, (area, _))| *area > 0.0)
+                .map(|(cell_index, (area, moment))| CellMeasurement {
+                    cell_index: *cell_index,
+                    area: *area,
+                    centroid: to_4d(&(moment / *area)),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Statistics about the lengths of the edges of a mesh.
+#[derive(Copy, Clone, Debug)]
+pub struct EdgeStatistics {
+    /// The number of edges
+    pub count: usize,
+
+    /// The length of the shortest edge
+    pub min: f32,
+
+    /// The length of the longest edge
+    pub max: f32,
+
+    /// The mean edge length
+    pub mean: f32,
+
+    /// The standard deviation of the edge lengths, which is zero for uniform polytopes
+    pub standard_deviation: f32,
+}
+
+impl EdgeStatistics {
+    /// Computes statistics about a list of edge `lengths`, returning `None` if it is empty.
+    pub fn new(lengths: &[f32]) -> Option<EdgeStatistics> {
+        if lengths.is_empty() {
+            return None;
+        }
+
+        let count = lengths.len();
+        let mean = lengths.iter().sum::<f32>() / count as f32;
+        let variance = lengths
+            .iter()
+            .map(|length| (length - mean) * (length - mean))
+            .sum::<f32>()
+            / count as f32;
+
+        Some(EdgeStatistics {
+            count,
+            min: lengths.iter().cloned().fold(f32::MAX, f32::min),
+            max: lengths.iter().cloned().fold(f32::MIN, f32::max),
+            mean,
+            standard_deviation: variance.sqrt(),
+        })
+    }
+}
+
+/// A series of measurements of a mesh, taken as a hyperplane with a fixed normal vector
+/// is swept through it. For example, sweeping a hyperplane with normal `<1, 1, 1, 1>`
+/// through the tesseract gives its "vertex-first" volume profile.
+pub struct Sweep {
+    /// The normal vector of the slicing hyperplane
+    normal: Vector4<f32>,
+
+    /// Each of the hyperplane displacements that were sampled, along with the measurements
+    /// at that displacement
+    samples: Vec<(f32, SliceMeasurements)>,
+}
+
+impl Sweep {
+    /// Measures `mesh` (taking into account its current transform) at `steps` evenly
+    /// spaced hyperplane displacements between `start` and `end` (inclusive).
+    pub fn new(mesh: &Mesh, normal: Vector4<f32>, start: f32, end: f32, steps: usize) -> Sweep {
+        let samples = (0..steps)
+            .map(|step| {
+                let t = if steps > 1 {
+                    step as f32 / (steps - 1) as f32
+                } else {
+                    0.0
+                };
+                let displacement = start + (end - start) * t;
+                let hyperplane = Hyperplane::new(normal, displacement);
+
+                (displacement, mesh.measure_slice(&hyperplane))
+            })
+            .collect();
+
+        Sweep {
+            normal: normal.normalize(),
+            samples,
+        }
+    }
+
+    /// Returns the (normalized) normal vector of the slicing hyperplane.
+    pub fn get_normal(&self) -> Vector4<f32> {
+        self.normal
+    }
+
+    /// Returns each of the hyperplane displacements that were sampled, along with the
+    /// measurements at that displacement.
+    pub fn get_samples(&self) -> &Vec<(f32, SliceMeasurements)> {
+        &self.samples
+    }
+
+    /// Writes this sweep to the file at `path` as CSV.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)
+    }
+
+    /// Writes this sweep as CSV, with one row per sample. Since the hyperplane is
+    /// `n·x + d = 0`, the distance from the origin along the normal is `-d`.
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "displacement,distance,volume,surface_area,centroid_x,centroid_y,centroid_z,centroid_w,cells"
+        )?;
+        for (displacement, measurements) in self.samples.iter() {
+            let centroid = measurements.centroid;
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{}",
+                displacement,
+                -displacement,
+                measurements.volume,
+                measurements.surface_area,
+                centroid.x,
+                centroid.y,
+                centroid.z,
+                centroid.w,
+                measurements.cells.len()
+            )?;
+        }
+
+        Ok(())
+    }
+}
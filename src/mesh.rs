@@ -1,30 +1,36 @@
+use std::collections::{HashMap, HashSet};
 use std::f32;
-use std::mem;
-use std::os::raw::c_void;
-use std::path::Path;
-use std::ptr;
 
-use cgmath::{self, Array, InnerSpace, Matrix4, SquareMatrix, Vector4, Zero};
-use gl;
-use gl::types::*;
+use cgmath::{InnerSpace, Matrix4, SquareMatrix, Vector4, Zero};
 
+use complex::BoundaryComplex;
 use hyperplane::Hyperplane;
 use math;
-use polychora::{Definition, Polychoron};
-use program::Program;
-use tetrahedron::Tetrahedron;
+use measure::{EdgeStatistics, SliceMeasurements};
+use polychora::{Definition, Polytope};
+use schlegel::SchlegelDiagram;
+use tetrahedron::{Slice, Tetrahedron};
+use topology::TopologyError;
+use unfold::Net;
 use utilities;
+use weld::{self, SlicePolyhedron};
 
-/// A struct representing an entry in the indirect draw buffer.
-#[repr(C)]
-struct DrawCommand {
-    count: u32,
-    instance_count: u32,
-    first: u32,
-    base_instance: u32,
+/// The cross-section of a single cell of a mesh: see `Mesh::slice_cells(...)`.
+#[derive(Clone, Debug)]
+pub struct CellSlice {
+    /// The index of the cell that was sliced
+    pub cell_index: u32,
+
+    /// The centroid of the cell that was sliced, which is used for coloring
+    pub cell_centroid: Vector4<f32>,
+
+    /// The vertices of the (convex) polygon, in order around its boundary
+    pub vertices: Vec<Vector4<f32>>,
 }
 
-/// A 4-dimensional mesh.
+/// A 4-dimensional mesh: the topology of a polytope (or some other shape), along with its
+/// decomposition into tetrahedra. This does not depend on OpenGL: see `Renderer` for
+/// drawing a mesh.
 pub struct Mesh {
     /// The vertices of the 4-dimensional mesh.
     vertices: Vec<Vector4<f32>>,
@@ -32,92 +38,110 @@ pub struct Mesh {
     /// The edges of the 4-dimensional mesh.
     edges: Vec<u32>,
 
-    /// The faces of the 4-dimensional mesh.
-    faces: Vec<u32>,
+    /// The faces of the 4-dimensional mesh, each of which is a list of vertex indices.
+    faces: Vec<Vec<u32>>,
 
-    /// The type of polychoron that this mesh represents.
-    polychoron: Polychoron,
+    /// The hyperplanes that bound each of the cells of this mesh (its H-representation).
+    h_representation: Vec<Hyperplane>,
 
-    /// The topology (definition) of the polychoron that this mesh represents.
+    /// The topology (definition) of the polytope that this mesh represents.
     def: Definition,
 
     /// A list of tetrahedra (embedded in 4-dimensions) that make up this mesh.
     tetrahedra: Vec<Tetrahedron>,
 
-    /// The current transform (translation, rotation, scale) of this mesh (in 4-dimensions).
-    transform: Matrix4<f32>,
-
-    /// The compute shader that is used to compute 3-dimensional slices of this mesh.
-    compute: Program,
-
-    /// The vertex array object (VAO) that is used for drawing a 3D slice of this mesh.
-    vao_slice: u32,
-
-    /// A GPU-side buffer that contains all of the tetrahedra that make up this mesh.
-    buffer_tetrahedra: u32,
+    /// The faces of each cell of this mesh, as indices into `faces` (this will be empty
+    /// if the mesh was built directly from tetrahedra).
+    cell_faces: Vec<Vec<u32>>,
 
-    /// A GPU-side buffer that contains all of the colors used to render 3-dimensional slices of this mesh.
-    buffer_slice_colors: u32,
+    /// The edges of each cell of this mesh, as pairs of vertex indices (this will be
+    /// empty if the mesh was built directly from tetrahedra).
+    cell_edges: Vec<Vec<u32>>,
 
-    /// A GPU-side buffer that contains all of the vertices that make up the active 3-dimensional cross-section of this mesh.
-    buffer_slice_vertices: u32,
+    /// The centroid of each cell of this mesh.
+    cell_centroids: Vec<Vector4<f32>>,
 
-    /// A GPU-side buffer that will be filled with indirect drawing commands via the `compute` program.
-    buffer_indirect_commands: u32,
-
-    /// The VAO that is used for drawing all of the tetrahedra that make up this mesh.
-    vao_tetrahedra: u32,
-
-    /// The EBO that is used for drawing all of the edges of the tetrahedra that make up this mesh.
-    ebo_tetrahedra: u32,
-
-    /// The VAO that is used for drawing the wireframe of this polychoron.
-    vao_edges: u32,
-
-    /// A GPU-side buffer that contains all of the unique vertices that make up this polychoron.
-    vbo_edges: u32,
-
-    /// The EBO that is used for drawing the wireframe of this polychoron.
-    ebo_edges: u32,
+    /// The current transform (translation, rotation, scale) of this mesh (in 4-dimensions).
+    transform: Matrix4<f32>,
 }
 
 impl Mesh {
-    pub fn new(polychoron: Polychoron) -> Mesh {
-        match polychoron {
-            Polychoron::Cell24Rectified => {
-                panic!("Drawing of this shape is not yet supported - please try another polychoron")
-            }
-            _ => (),
-        }
+    /// Intersection points that are closer than this are merged by `slice_cells(...)`.
+    const WELD_TOLERANCE: f32 = 1.0e-5;
+
+    /// Creates a new mesh from any `polytope`, i.e. one of the built-in `Polychoron`s or a
+    /// shape that was loaded from disk.
+    pub fn new<T: Polytope>(polytope: T) -> Mesh {
+        let mut mesh = Mesh::with_topology(
+            polytope.get_vertices(),
+            polytope.get_edges(),
+            polytope.get_faces(),
+            polytope.get_h_representation(),
+            polytope.get_definition(),
+        );
 
-        let compute = utilities::load_file_as_string(Path::new("shaders/compute_slice.glsl"));
+        mesh.tetrahedralize();
+        mesh
+    }
 
-        let mut mesh = Mesh {
-            vertices: polychoron.get_vertices(),
-            edges: polychoron.get_edges(),
-            faces: polychoron.get_faces(),
-            polychoron,
-            def: polychoron.get_definition(),
-            tetrahedra: Vec::new(),
-            transform: Matrix4::identity(),
-            compute: Program::single_stage(compute).unwrap(),
-            vao_slice: 0,
-            buffer_tetrahedra: 0,
-            buffer_slice_colors: 0,
-            buffer_slice_vertices: 0,
-            buffer_indirect_commands: 0,
-            vao_tetrahedra: 0,
-            ebo_tetrahedra: 0,
-            vao_edges: 0,
-            vbo_edges: 0,
-            ebo_edges: 0,
+    /// Creates a new mesh directly from a list of `tetrahedra`, i.e. ones produced by a
+    /// procedural generator (see the `primitives` module), a file loader, or a simulation.
+    /// Each tetrahedron's `cell_index` and `cell_centroid` are used as-is.
+    ///
+    /// Optionally, `skeleton` can contain a list of vertices along with pairs of indices
+    /// into that list, which make up the skeleton (wireframe) of this mesh. Otherwise, the
+    /// mesh will have no edges.
+    pub fn from_tetrahedra(
+        tetrahedra: Vec<Tetrahedron>,
+        skeleton: Option<(Vec<Vector4<f32>>, Vec<u32>)>,
+    ) -> Mesh {
+        let (vertices, edges) = skeleton.unwrap_or_default();
+
+        // There are no faces or cells to count here, other than the number of (unique)
+        // cell indices.
+        let mut cell_indices = tetrahedra
+            .iter()
+            .map(|tetrahedron| tetrahedron.get_cell_index())
+            .collect::<Vec<_>>();
+        cell_indices.sort();
+        cell_indices.dedup();
+
+        let def = Definition {
+            components_per_vertex: 4,
+            vertices_per_edge: 2,
+            vertices_per_face: 0,
+            vertices_per_cell: 0,
+            faces_per_cell: 0,
+            cells: cell_indices.len() as u32,
         };
 
-        mesh.tetrahedralize();
-        mesh.init_render_objects();
+        let mut mesh = Mesh::with_topology(vertices, edges, Vec::new(), Vec::new(), def);
+        mesh.tetrahedra = tetrahedra;
         mesh
     }
 
+    /// Creates a mesh with the given topology, but without any tetrahedra.
+    fn with_topology(
+        vertices: Vec<Vector4<f32>>,
+        edges: Vec<u32>,
+        faces: Vec<Vec<u32>>,
+        h_representation: Vec<Hyperplane>,
+        def: Definition,
+    ) -> Mesh {
+        Mesh {
+            vertices,
+            edges,
+            faces,
+            h_representation,
+            def,
+            tetrahedra: Vec::new(),
+            cell_faces: Vec::new(),
+            cell_edges: Vec::new(),
+            cell_centroids: Vec::new(),
+            transform: Matrix4::identity(),
+        }
+    }
+
     /// Returns an array of all of the tetrahedra that make up this mesh.
     pub fn get_tetrahedra(&self) -> &Vec<Tetrahedron> {
         &self.tetrahedra
@@ -135,7 +159,7 @@ impl Mesh {
 
     /// Returns the number of unique faces in this mesh.
     pub fn get_number_of_faces(&self) -> usize {
-        self.faces.len() / self.def.vertices_per_face as usize
+        self.faces.len()
     }
 
     /// Returns the `i`th vertex of this mesh.
@@ -156,16 +180,253 @@ impl Mesh {
     /// Returns an unordered list of the unique vertices that make up the `i`th
     /// face of this mesh.
     pub fn get_vertices_for_face(&self, i: u32) -> Vec<Vector4<f32>> {
-        let idx_face_s = (i * self.def.vertices_per_face) as usize;
-        let idx_face_e = (i * self.def.vertices_per_face + self.def.vertices_per_face) as usize;
-        let vertex_ids = &self.faces[idx_face_s..idx_face_e];
-
-        let vertices = vertex_ids
+        self.faces[i as usize]
             .iter()
             .map(|id| self.get_vertex(*id))
+            .collect::<Vec<_>>()
+    }
+
+    /// Returns the unique vertices of this mesh.
+    pub fn get_vertices(&self) -> &Vec<Vector4<f32>> {
+        &self.vertices
+    }
+
+    /// Returns the faces of this mesh, each of which is an (unordered) list of indices
+    /// into `get_vertices(...)`.
+    pub fn get_faces(&self) -> &Vec<Vec<u32>> {
+        &self.faces
+    }
+
+    /// Returns the faces of each cell of this mesh, as indices into `get_faces(...)`. This
+    /// is empty if the mesh was built directly from tetrahedra.
+    pub fn get_cell_faces(&self) -> &Vec<Vec<u32>> {
+        &self.cell_faces
+    }
+
+    /// Builds the boundary complex of this mesh, which can answer incidence queries
+    /// between its vertices, edges, faces, and cells. See `BoundaryComplex::new(...)`.
+    pub fn get_boundary_complex(&self) -> Option<BoundaryComplex> {
+        BoundaryComplex::new(self)
+    }
+
+    /// Unfolds this (convex) mesh into a 3-dimensional net along a breadth-first spanning
+    /// tree of its cells. See `Net::new(...)`.
+    pub fn get_net(&self) -> Option<Net> {
+        Net::new(self)
+    }
+
+    /// Builds the Schlegel diagram of this (convex) mesh, projected through its `cell`th
+    /// cell. See `SchlegelDiagram::new(...)`.
+    pub fn get_schlegel_diagram(&self, cell: u32) -> Option<SchlegelDiagram> {
+        SchlegelDiagram::new(self, cell)
+    }
+
+    /// Builds the dual of this (convex) mesh, which has a vertex for each cell of this
+    /// mesh, an edge for each face, a face for each edge, and a cell for each vertex.
+    /// Meshes that were built directly from tetrahedra don't have any cells, in which
+    /// case this returns `None`.
+    ///
+    /// The dual is constructed by polar reciprocation about the boundary centroid, using
+    /// a hypersphere whose radius is the average distance to the cells. This guarantees
+    /// that the faces of the dual are planar, and for any regular polychoron, each of the
+    /// dual's vertices is exactly at the centroid of the corresponding cell.
+    pub fn get_dual(&self) -> Option<Mesh> {
+        let complex = self.get_boundary_complex()?;
+        let center = self.get_boundary_centroid();
+
+        // The distance from the center to each cell (along with the direction).
+        let offsets = self
+            .h_representation
+            .iter()
+            .map(|hyperplane| hyperplane.normal * -hyperplane.signed_distance(&center))
+            .collect::<Vec<_>>();
+        let radius =
+            offsets.iter().map(|offset| offset.magnitude()).sum::<f32>() / offsets.len() as f32;
+
+        // Each cell becomes a vertex.
+        let vertices = offsets
+            .iter()
+            .map(|offset| center + offset * (radius * radius / offset.magnitude2()))
+            .collect::<Vec<_>>();
+
+        // Each face (which joins two cells) becomes an edge.
+        let edges = (0..complex.get_number_of_faces() as u32)
+            .flat_map(|face| complex.get_cells_of_face(face).to_vec())
+            .collect::<Vec<_>>();
+
+        // Each edge (which is surrounded by a ring of cells) becomes a face.
+        let faces = (0..complex.get_number_of_edges() as u32)
+            .map(|edge| complex.get_cells_around_edge(edge))
             .collect::<Vec<_>>();
 
-        vertices
+        // Each vertex (which is surrounded by the edges that meet there) becomes a cell,
+        // which is bounded by the polar hyperplane of the vertex.
+        let cells = (0..complex.get_number_of_vertices() as u32)
+            .map(|vertex| complex.get_edges_around_vertex(vertex).to_vec())
+            .collect::<Vec<_>>();
+        let h_representation = self
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let direction = vertex - center;
+                let distance = radius * radius / direction.magnitude();
+                Hyperplane::new(direction, -(center.dot(direction.normalize()) + distance))
+            })
+            .collect::<Vec<_>>();
+
+        let def = Definition::from_topology(&faces, &cells);
+        let mut dual = Mesh::with_topology(vertices, edges, faces, h_representation, def);
+        dual.tetrahedralize();
+
+        Some(dual)
+    }
+
+    /// Returns a copy of this mesh that is uniformly scaled by `factor` about the origin.
+    pub fn scaled(&self, factor: f32) -> Mesh {
+        Mesh {
+            vertices: self.vertices.iter().map(|vertex| vertex * factor).collect(),
+            edges: self.edges.clone(),
+            faces: self.faces.clone(),
+            h_representation: self
+                .h_representation
+                .iter()
+                .map(|hyperplane| Hyperplane {
+                    normal: hyperplane.normal,
+                    displacement: hyperplane.displacement * factor,
+                })
+                .collect(),
+            def: self.def,
+            tetrahedra: self
+                .tetrahedra
+                .iter()
+                .map(|tetrahedron| {
+                    let vertices = tetrahedron.get_vertices();
+                    Tetrahedron::new(
+                        [
+                            vertices[0] * factor,
+                            vertices[1] * factor,
+                            vertices[2] * factor,
+                            vertices[3] * factor,
+                        ],
+                        tetrahedron.cell_index,
+                        tetrahedron.cell_centroid * factor,
+                    )
+                })
+                .collect(),
+            cell_faces: self.cell_faces.clone(),
+            cell_edges: self.cell_edges.clone(),
+            cell_centroids: self
+                .cell_centroids
+                .iter()
+                .map(|centroid| centroid * factor)
+                .collect(),
+            transform: self.transform,
+        }
+    }
+
+    /// Returns the compound of this mesh and `other`: a single (generally non-convex)
+    /// mesh that contains all of the vertices, edges, faces, cells, and tetrahedra of
+    /// both. The cells of `other` are numbered after the cells of this mesh. If either
+    /// mesh was built directly from tetrahedra, the compound won't have any faces or cells.
+    pub fn compound(&self, other: &Mesh) -> Mesh {
+        let vertex_offset = self.vertices.len() as u32;
+        let face_offset = self.faces.len() as u32;
+        let cell_offset = self
+            .tetrahedra
+            .iter()
+            .map(|tetrahedron| tetrahedron.cell_index + 1)
+            .max()
+            .unwrap_or(0)
+            .max(self.cell_faces.len() as u32);
+
+        let offset_all = |indices: &[u32], offset: u32| {
+            indices
+                .iter()
+                .map(|index| index + offset)
+                .collect::<Vec<_>>()
+        };
+
+        let mut faces = self.faces.clone();
+        faces.extend(
+            other
+                .faces
+                .iter()
+                .map(|face| offset_all(face, vertex_offset)),
+        );
+
+        let mut cell_faces = self.cell_faces.clone();
+        cell_faces.extend(
+            other
+                .cell_faces
+                .iter()
+                .map(|cell| offset_all(cell, face_offset)),
+        );
+
+        let mut cell_edges = self.cell_edges.clone();
+        cell_edges.extend(
+            other
+                .cell_edges
+                .iter()
+                .map(|edges| offset_all(edges, vertex_offset)),
+        );
+
+        let mut tetrahedra = self.tetrahedra.clone();
+        tetrahedra.extend(other.tetrahedra.iter().map(|tetrahedron| {
+            Tetrahedron::new(
+                *tetrahedron.get_vertices(),
+                tetrahedron.cell_index + cell_offset,
+                tetrahedron.cell_centroid,
+            )
+        }));
+
+        let mut def = Definition::from_topology(&faces, &cell_faces);
+        def.cells = self.def.cells + other.def.cells;
+
+        let mut compound = Mesh {
+            vertices: [&self.vertices[..], &other.vertices[..]].concat(),
+            edges: [
+                &self.edges[..],
+                &offset_all(&other.edges, vertex_offset)[..],
+            ]
+            .concat(),
+            faces,
+            h_representation: [&self.h_representation[..], &other.h_representation[..]].concat(),
+            def,
+            tetrahedra,
+            cell_faces,
+            cell_edges,
+            cell_centroids: [&self.cell_centroids[..], &other.cell_centroids[..]].concat(),
+            transform: self.transform,
+        };
+
+        // The faces and cells of the compound are only meaningful if both meshes have them.
+        if self.cell_faces.is_empty() || other.cell_faces.is_empty() {
+            compound.faces.clear();
+            compound.h_representation.clear();
+            compound.cell_faces.clear();
+            compound.cell_edges.clear();
+            compound.cell_centroids.clear();
+            compound.def.vertices_per_face = 0;
+            compound.def.vertices_per_cell = 0;
+            compound.def.faces_per_cell = 0;
+        }
+
+        compound
+    }
+
+    /// Returns the edges of this mesh, as pairs of indices into `get_vertices(...)`.
+    pub fn get_edges(&self) -> &Vec<u32> {
+        &self.edges
+    }
+
+    /// Returns the topology (definition) of the polytope that this mesh represents.
+    pub fn get_definition(&self) -> Definition {
+        self.def
+    }
+
+    /// Returns this mesh's current transform (in 4-dimensions).
+    pub fn get_transform(&self) -> &Matrix4<f32> {
+        &self.transform
     }
 
     /// Set this mesh's current transform (in 4-dimensions). This will affect how the
@@ -174,79 +435,323 @@ impl Mesh {
         self.transform = *transform;
     }
 
-    /// Slice this mesh with a 4-dimensional `hyperplane`.
-    pub fn slice(&mut self, hyperplane: &Hyperplane) {
-        self.compute.bind();
-        self.compute
-            .uniform_4f("u_hyperplane_normal", &hyperplane.normal);
-        self.compute
-            .uniform_1f("u_hyperplane_displacement", hyperplane.displacement);
+    /// Returns the total (3-dimensional) volume of the boundary of this mesh, which is the
+    /// sum of the volumes of its tetrahedra.
+    pub fn get_boundary_volume(&self) -> f32 {
+        self.tetrahedra
+            .iter()
+            .map(|tetrahedron| tetrahedron.get_volume())
+            .sum()
+    }
 
-        self.compute
-            .uniform_matrix_4f("u_transform", &self.transform);
+    /// Returns the centroid of the boundary of this mesh (i.e. the average of the
+    /// centroids of its tetrahedra, weighted by their volumes). For any of the regular
+    /// polychora, this is the origin.
+    pub fn get_boundary_centroid(&self) -> Vector4<f32> {
+        let mut centroid = Vector4::zero();
+        for tetrahedron in self.tetrahedra.iter() {
+            let vertices = tetrahedron.get_vertices();
+            centroid += utilities::average(vertices, &Vector4::zero()) * tetrahedron.get_volume();
+        }
 
-        unsafe {
-            // Bind buffers for read / write.
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 0, self.buffer_tetrahedra);
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 1, self.buffer_slice_vertices);
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 2, self.buffer_indirect_commands);
+        centroid / self.get_boundary_volume()
+    }
 
-            let dispatch = (self.tetrahedra.len() as f32 / 128.0).ceil();
-            gl::DispatchCompute(dispatch as u32, 1, 1);
+    /// Returns the 4-dimensional content (hypervolume) enclosed by this mesh. Each
+    /// tetrahedron is connected to the boundary centroid, forming a 4-simplex: the
+    /// hypervolume is the sum of the contents of all of these simplices. This assumes that
+    /// the mesh is star-shaped with respect to its boundary centroid, which is true for
+    /// any convex polytope (but not, for example, a Clifford torus).
+    pub fn get_hypervolume(&self) -> f32 {
+        let center = self.get_boundary_centroid();
 
-            // Barrier against subsequent SSBO reads and indirect drawing commands.
-            gl::MemoryBarrier(gl::SHADER_STORAGE_BARRIER_BIT | gl::COMMAND_BARRIER_BIT);
-        }
+        self.tetrahedra
+            .iter()
+            .map(|tetrahedron| {
+                // The content of a 4-simplex is a quarter of the volume of its base times
+                // its height.
+                let height = tetrahedron
+                    .get_normal()
+                    .dot(tetrahedron.get_vertices()[0] - center)
+                    .abs();
+                tetrahedron.get_volume() * height / 4.0
+            })
+            .sum()
+    }
+
+    /// Returns the radius of the smallest hypersphere, centered at the boundary centroid,
+    /// that contains all of the vertices of this mesh.
+    pub fn get_circumradius(&self) -> f32 {
+        let center = self.get_boundary_centroid();
 
-        self.compute.unbind();
+        self.tetrahedra
+            .iter()
+            .flat_map(|tetrahedron| tetrahedron.get_vertices().iter())
+            .map(|vertex| (vertex - center).magnitude())
+            .fold(0.0, f32::max)
     }
 
-    /// Draws a 3-dimensional slice of the 4-dimensional mesh.
-    pub fn draw_slice(&self) {
-        unsafe {
-            gl::BindVertexArray(self.vao_slice);
+    /// Returns the radius of the largest hypersphere, centered at the boundary centroid,
+    /// that fits inside of this mesh: this is the shortest distance from the center to
+    /// the hyperplane of any tetrahedron. For a convex polytope, these hyperplanes are
+    /// the ones that bound its cells.
+    pub fn get_inradius(&self) -> f32 {
+        let center = self.get_boundary_centroid();
 
-            // Bind the buffer that contains indirect draw commands.
-            gl::BindBuffer(gl::DRAW_INDIRECT_BUFFER, self.buffer_indirect_commands);
+        self.tetrahedra
+            .iter()
+            .map(|tetrahedron| {
+                tetrahedron
+                    .get_normal()
+                    .dot(tetrahedron.get_vertices()[0] - center)
+                    .abs()
+            })
+            .fold(f32::MAX, f32::min)
+    }
 
-            // Dispatch indirect draw commands.
-            gl::MultiDrawArraysIndirect(
-                gl::TRIANGLES,
-                ptr::null() as *const GLvoid,
-                self.tetrahedra.len() as i32,
-                mem::size_of::<DrawCommand>() as i32,
-            );
+    /// Returns statistics about the lengths of the edges of this mesh, or `None` if the
+    /// mesh doesn't have any edges.
+    pub fn get_edge_statistics(&self) -> Option<EdgeStatistics> {
+        let lengths = (0..self.get_number_of_edges())
+            .map(|i| {
+                let (a, b) = self.get_vertices_for_edge(i as u32);
+                (b - a).magnitude()
+            })
+            .collect::<Vec<_>>();
+
+        EdgeStatistics::new(&lengths)
+    }
+
+    /// Checks that the topology of this mesh is sound, returning every problem that was
+    /// found. In particular:
+    ///
+    /// - the Euler characteristic `V - E + F - C` must be zero
+    /// - every face must be shared by exactly two cells
+    /// - each cell must have the number of faces given by the mesh's definition (if this
+    ///   is uniform), and at least 4
+    /// - every cell must be closed, i.e. each edge of a cell belongs to exactly two of its
+    ///   faces
+    ///
+    /// Meshes that were built directly from tetrahedra don't have any faces or cells, so
+    /// they can't be validated.
+    pub fn validate(&self) -> Result<(), Vec<TopologyError>> {
+        if self.cell_faces.is_empty() {
+            return Err(vec![TopologyError::MissingTopology]);
+        }
+
+        let mut errors = Vec::new();
+
+        let (vertices, edges) = (self.get_number_of_vertices(), self.get_number_of_edges());
+        let (faces, cells) = (self.get_number_of_faces(), self.cell_faces.len());
+        if vertices + faces != edges + cells {
+            errors.push(TopologyError::EulerCharacteristic {
+                vertices,
+                edges,
+                faces,
+                cells,
+            });
+        }
+
+        let mut cells_per_face = vec![0; faces];
+        for face_indices in self.cell_faces.iter() {
+            for face_index in face_indices.iter() {
+                cells_per_face[*face_index as usize] += 1;
+            }
+        }
+        for (face, cells) in cells_per_face.iter().enumerate() {
+            if *cells != 2 {
+                errors.push(TopologyError::FaceNotShared {
+                    face,
+                    cells: *cells,
+                });
+            }
+        }
+
+        // The edges of each face, as (sorted) pairs of vertex indices: since faces are
+        // unordered lists of vertices, these are the edges of the mesh whose endpoints both
+        // belong to the face.
+        let all_edges = self
+            .edges
+            .chunks(2)
+            .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])))
+            .collect::<HashSet<_>>();
+        let edges_per_face = self
+            .faces
+            .iter()
+            .map(|face| {
+                let mut edges = Vec::new();
+                for (i, a) in face.iter().enumerate() {
+                    for b in face[i + 1..].iter() {
+                        let edge = (*a.min(b), *a.max(b));
+                        if all_edges.contains(&edge) {
+                            edges.push(edge);
+                        }
+                    }
+                }
+                edges
+            })
+            .collect::<Vec<_>>();
+
+        for (cell, face_indices) in self.cell_faces.iter().enumerate() {
+            let expected = self.def.faces_per_cell as usize;
+            if expected != 0 && face_indices.len() != expected {
+                errors.push(TopologyError::WrongNumberOfFaces {
+                    cell,
+                    expected,
+                    found: face_indices.len(),
+                });
+            }
+            if face_indices.len() < 4 {
+                errors.push(TopologyError::DegenerateCell {
+                    cell,
+                    faces: face_indices.len(),
+                });
+                continue;
+            }
+
+            let mut faces_per_edge = HashMap::new();
+            for face_index in face_indices.iter() {
+                for edge in edges_per_face[*face_index as usize].iter() {
+                    *faces_per_edge.entry(*edge).or_insert(0) += 1;
+                }
+            }
+            let open_edges = faces_per_edge.values().filter(|count| **count != 2).count();
+            if open_edges != 0 {
+                errors.push(TopologyError::CellNotClosed { cell, open_edges });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 
-    /// Draws a 3-dimensional projection of the 4-dimensional tetrahedra that make up this
-    /// mesh.
-    pub fn draw_tetrahedra(&self) {
-        unsafe {
-            let number_of_tetrahedral_edges =
-                self.tetrahedra.len() * Tetrahedron::get_number_of_edges() * 2;
+    /// Slices each of the tetrahedra that make up this mesh with `hyperplane`, taking into
+    /// account the mesh's current transform. This is a CPU reference implementation of
+    /// `Renderer::slice(...)`: the `i`th slice corresponds to the `i`th tetrahedron.
+    pub fn slice(&self, hyperplane: &Hyperplane) -> Vec<Slice> {
+        self.tetrahedra
+            .iter()
+            .map(|tetrahedron| tetrahedron.slice_with_transform(&self.transform, hyperplane))
+            .collect()
+    }
 
-            gl::BindVertexArray(self.vao_tetrahedra);
-            gl::DrawElements(
-                gl::LINES,
-                number_of_tetrahedral_edges as i32,
-                gl::UNSIGNED_INT,
-                ptr::null(),
-            );
+    /// Slices this mesh with `hyperplane` and welds the resulting slices into a set of
+    /// closed polyhedra, where each face is the slice of a single cell. See
+    /// `weld::weld_slices(...)` for details.
+    pub fn slice_polyhedra(&self, hyperplane: &Hyperplane) -> Vec<SlicePolyhedron> {
+        weld::weld_slices(&self.tetrahedra, &self.slice(hyperplane), hyperplane)
+    }
+
+    /// Measures the cross-section of this mesh with `hyperplane` (its volume, surface
+    /// area, centroid, and the contribution of each cell), taking into account the
+    /// mesh's current transform.
+    pub fn measure_slice(&self, hyperplane: &Hyperplane) -> SliceMeasurements {
+        SliceMeasurements::new(&self.slice_polyhedra(hyperplane), hyperplane)
+    }
+
+    /// Slices each of the cells of this mesh with `hyperplane` directly (rather than
+    /// slicing the tetrahedra that make up each cell), taking into account the mesh's
+    /// current transform. Each cell is convex, so its cross-section is a single convex
+    /// polygon: this is found by intersecting the cell's edges with the hyperplane.
+    ///
+    /// Meshes that were built directly from tetrahedra don't know the edges of their
+    /// cells, so in this case, the polygons are recovered by welding together the
+    /// slices of the tetrahedra instead (see `slice_polyhedra(...)`).
+    pub fn slice_cells(&self, hyperplane: &Hyperplane) -> Vec<CellSlice> {
+        if self.cell_edges.is_empty() {
+            return self.slice_cells_from_tetrahedra(hyperplane);
         }
+
+        let vertices = self
+            .vertices
+            .iter()
+            .map(|vertex| self.transform * vertex)
+            .collect::<Vec<_>>();
+        let distances = vertices
+            .iter()
+            .map(|vertex| hyperplane.signed_distance(vertex))
+            .collect::<Vec<_>>();
+
+        let mut slices = Vec::new();
+        for (cell_index, edges) in self.cell_edges.iter().enumerate() {
+            let mut points: Vec<Vector4<f32>> = Vec::new();
+
+            for pair in edges.chunks(2) {
+                let (a, b) = (pair[0] as usize, pair[1] as usize);
+                let (da, db) = (distances[a], distances[b]);
+
+                // Vertices that lie in the hyperplane are handled separately below.
+                if da == 0.0 || db == 0.0 || da.signum() == db.signum() {
+                    continue;
+                }
+
+                let t = da / (da - db);
+                points.push(vertices[a] + (vertices[b] - vertices[a]) * t);
+            }
+            for pair in edges.chunks(2) {
+                for index in pair.iter() {
+                    if distances[*index as usize] == 0.0 {
+                        points.push(vertices[*index as usize]);
+                    }
+                }
+            }
+
+            // Remove duplicate points, i.e. where an edge meets the hyperplane at one of its
+            // vertices.
+            let mut unique: Vec<Vector4<f32>> = Vec::new();
+            for point in points {
+                if unique
+                    .iter()
+                    .all(|other| (other - point).magnitude() > Mesh::WELD_TOLERANCE)
+                {
+                    unique.push(point);
+                }
+            }
+
+            if unique.len() >= 3 {
+                let order = math::sort_indices_on_plane(&unique);
+                slices.push(CellSlice {
+                    cell_index: cell_index as u32,
+                    cell_centroid: self.cell_centroids[cell_index],
+                    vertices: order.iter().map(|i| unique[*i]).collect(),
+                });
+            }
+        }
+
+        slices
     }
 
-    /// Draws a 3-dimensional projection of the skeleton (wireframe) of this polychoron.
-    pub fn draw_edges(&self) {
-        unsafe {
-            gl::BindVertexArray(self.vao_edges);
-            gl::DrawElements(
-                gl::LINES,
-                self.edges.len() as i32,
-                gl::UNSIGNED_INT,
-                ptr::null(),
-            );
+    /// Recovers the polygonal cross-section of each cell from the welded slices of the
+    /// tetrahedra that make up this mesh.
+    fn slice_cells_from_tetrahedra(&self, hyperplane: &Hyperplane) -> Vec<CellSlice> {
+        let mut cell_centroids = HashMap::new();
+        for tetrahedron in self.tetrahedra.iter() {
+            cell_centroids.insert(tetrahedron.cell_index, tetrahedron.cell_centroid);
+        }
+
+        let mut slices = Vec::new();
+        for polyhedron in self.slice_polyhedra(hyperplane) {
+            for (face, cell_index) in polyhedron
+                .get_faces()
+                .iter()
+                .zip(polyhedron.get_cell_indices().iter())
+            {
+                slices.push(CellSlice {
+                    cell_index: *cell_index,
+                    cell_centroid: cell_centroids[cell_index],
+                    vertices: face
+                        .iter()
+                        .map(|index| polyhedron.get_vertices()[*index as usize])
+                        .collect(),
+                });
+            }
         }
+        slices.sort_by_key(|slice| slice.cell_index);
+
+        slices
     }
 
     /// Given the H-representation of this polychoron, return a list of lists, where
@@ -264,17 +769,16 @@ impl Mesh {
     fn gather_cells(&self) -> Vec<(Hyperplane, Vec<u32>)> {
         let mut cells = Vec::new();
 
-        for hyperplane in self.polychoron.get_h_representation().iter() {
+        for hyperplane in self.h_representation.iter() {
             let mut faces_in_hyperplane = Vec::new();
 
             for face_index in 0..self.get_number_of_faces() {
                 let face_vertices = self.get_vertices_for_face(face_index as u32);
-                assert_eq!(face_vertices.len(), self.def.vertices_per_face as usize);
 
                 // Check if all of the vertices of this face are inside the bounding hyperplane.
                 let mut inside = true;
                 for vertex in face_vertices.iter() {
-                    if !hyperplane.inside(&vertex) {
+                    if !hyperplane.inside(vertex) {
                         inside = false;
                         break;
                     }
@@ -284,12 +788,6 @@ impl Mesh {
                 }
             }
 
-            dbg!(format!(
-                "{} faces found inside of the hyperplane: {:?}",
-                faces_in_hyperplane.len(),
-                hyperplane
-            ));
-
             cells.push((*hyperplane, faces_in_hyperplane));
         }
 
@@ -301,34 +799,57 @@ impl Mesh {
     /// Reference: `https://www.ics.uci.edu/~eppstein/projects/tetra/`
     fn tetrahedralize(&mut self) {
         let mut tetrahedrons = Vec::new();
+        let mut cell_faces = Vec::new();
+        let mut cell_edges = Vec::new();
+        let mut cell_centroids = Vec::new();
 
         for (cell_index, plane_and_faces) in self.gather_cells().iter().enumerate() {
-            let prev_len = tetrahedrons.len();
-
-            // The vertex that all tetrahedrons making up this solid will connect to.
-            let mut apex = Vector4::from_value(f32::MAX);
             let (hyperplane, face_indices) = plane_and_faces;
 
+            // The vertex that all tetrahedrons making up this solid will connect to. Each
+            // face is shared by two cells, so both cells need to triangulate it in the same
+            // way (otherwise, slices wouldn't be watertight). To guarantee this, the apex is
+            // the cell vertex with the smallest index, and each face is triangulated with a
+            // fan that starts at the face vertex with the smallest index.
+            let apex_index = face_indices
+                .iter()
+                .flat_map(|index| self.faces[*index as usize].iter())
+                .min()
+                .cloned()
+                .unwrap_or(0);
+            let apex = self.get_vertex(apex_index);
+
             // Calculate the centroid of this cell, which is the average of all face centroids.
             let cell_centroid = utilities::average(
                 &face_indices
                     .iter()
                     .map(|index| {
-                        let face_centroid = utilities::average(
-                            &self.get_vertices_for_face(*index),
-                            &Vector4::zero(),
-                        );
-
-                        face_centroid
+                        utilities::average(&self.get_vertices_for_face(*index), &Vector4::zero())
                     })
                     .collect::<Vec<_>>(),
                 &Vector4::zero(),
             );
 
-            dbg!(format!(
-                "Length of cell centroid: {}",
-                cell_centroid.magnitude()
-            ));
+            // The edges of this cell are the edges of the mesh whose endpoints are both
+            // vertices of this cell.
+            let mut cell_vertices = face_indices
+                .iter()
+                .flat_map(|index| self.faces[*index as usize].iter().cloned())
+                .collect::<Vec<_>>();
+            cell_vertices.sort();
+            cell_vertices.dedup();
+            cell_edges.push(
+                self.edges
+                    .chunks(2)
+                    .filter(|pair| {
+                        cell_vertices.binary_search(&pair[0]).is_ok()
+                            && cell_vertices.binary_search(&pair[1]).is_ok()
+                    })
+                    .flat_map(|pair| pair.iter().cloned())
+                    .collect::<Vec<_>>(),
+            );
+            cell_faces.push(face_indices.clone());
+            cell_centroids.push(cell_centroid);
 
             // Iterate over each face of the current cell.
             for face_index in face_indices {
@@ -347,11 +868,16 @@ impl Mesh {
                 // vertices. However, this assumes that our vertices are sorted in
                 // some order (clockwise or counter-clockwise). So, the first thing we
                 // do is, collect all of the face vertices and sort them.
-                let face_vertices_sorted = math::sort_points_on_plane(&face_vertices, &hyperplane);
+                let mut face_vertices_sorted =
+                    math::sort_points_on_plane(&face_vertices, hyperplane);
 
-                if apex.x == f32::MAX {
-                    apex = face_vertices[0];
-                }
+                let first =
+                    self.get_vertex(*self.faces[*face_index as usize].iter().min().unwrap());
+                let start = face_vertices_sorted
+                    .iter()
+                    .position(|vertex| *vertex == first)
+                    .unwrap_or(0);
+                face_vertices_sorted.rotate_left(start);
 
                 // We only want to tetrahedralize faces that are NOT connected to the apex.
                 if !face_vertices.contains(&apex) {
@@ -363,7 +889,7 @@ impl Mesh {
                         tetrahedrons.push(Tetrahedron::new(
                             [
                                 face_vertices_sorted[0],
-                                face_vertices_sorted[i + 0],
+                                face_vertices_sorted[i],
                                 face_vertices_sorted[i + 1],
                                 apex,
                             ],
@@ -373,300 +899,11 @@ impl Mesh {
                     }
                 }
             }
-
-            dbg!(format!(
-                "{} tetrahedrons found for cell at index: {}",
-                tetrahedrons.len() - prev_len,
-                cell_index
-            ));
         }
 
-        dbg!(format!(
-            "Mesh tetrahedralization resulted in {} tetrahedrons.",
-            tetrahedrons.len()
-        ));
-
         self.tetrahedra = tetrahedrons;
-    }
-
-    /// Gathers all of the necessary vertex attributes required to render the tetrahedra
-    /// that make up this mesh. In particular, this function returns the vertex positions
-    /// and colors.
-    fn gather_tetrahedra_attributes(&self) -> (Vec<Vector4<f32>>, Vec<Vector4<f32>>) {
-        // Set up CPU-side buffers.
-        let mut vertices = Vec::new();
-        let mut colors = Vec::new();
-
-        for tetra in self.tetrahedra.iter() {
-            // First, push back all of this tetrahedron's vertices.
-            vertices.extend_from_slice(tetra.get_vertices());
-
-            // Any tetrahedral slice can have at most 6 vertices (a quadrilateral, 2 triangles).
-            let max_vertices_per_slice = 6;
-
-            // Next, push back all of this tetrahedron's colors (currently, we are
-            // using the cell centroid to generate some sort of shading / colors).
-            // TODO: see notes on attribute divisors in `init_slice_objects(...)`
-            for i in 0..max_vertices_per_slice {
-                colors.push(tetra.cell_centroid);
-            }
-        }
-
-        (vertices, colors)
-    }
-
-    /// Gather all of the edge indices for the tetrahedra that make up this mesh.
-    fn gather_tetrahedra_indices(&self) -> Vec<u32> {
-        let mut indices = Vec::new();
-
-        // Gather the base indices used for drawing a tetrahedron, i.e.
-        // `[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]`, and apply
-        // relative offsets.
-        let local_indices = Tetrahedron::get_edge_indices();
-
-        for (i, tetra) in self.tetrahedra.iter().enumerate() {
-            // Generate a new set of edge indices for this tetrahedron.
-            for (a, b) in local_indices.iter() {
-                // Create a new set of indices to draw the current tetrahedron. First,
-                // we add `4 * i`, since each tetrahedron has 4 vertices.
-                let offset = (Tetrahedron::get_number_of_vertices() * i) as u32;
-
-                indices.push(a + offset);
-                indices.push(b + offset);
-            }
-        }
-
-        indices
-    }
-
-    /// Initializes all OpenGL objects (VAOs, buffers, etc.): see functions below.
-    fn init_render_objects(&mut self) {
-        self.init_slice_objects();
-        self.init_tetrahedra_objects();
-        self.init_edges_objects();
-    }
-
-    /// Initializes all OpenGL objects for rendering a 3-dimensional slice of this
-    /// 4-dimensional polychoron.
-    fn init_slice_objects(&mut self) {
-        unsafe {
-            gl::CreateVertexArrays(1, &mut self.vao_slice);
-
-            // Set up attribute #0: positions.
-            const ATTR_POS: u32 = 0;
-            const BINDING_POS: u32 = 0;
-            gl::EnableVertexArrayAttrib(self.vao_slice, ATTR_POS);
-            gl::VertexArrayAttribFormat(
-                self.vao_slice,
-                ATTR_POS,
-                self.def.components_per_vertex as i32,
-                gl::FLOAT,
-                gl::FALSE,
-                0,
-            );
-            gl::VertexArrayAttribBinding(self.vao_slice, ATTR_POS, BINDING_POS);
-
-            // Set up attribute #1: colors.
-            const ATTR_COL: u32 = 1;
-            const BINDING_COL: u32 = 1;
-            gl::EnableVertexArrayAttrib(self.vao_slice, ATTR_COL);
-            gl::VertexArrayAttribFormat(
-                self.vao_slice,
-                ATTR_COL,
-                self.def.components_per_vertex as i32,
-                gl::FLOAT,
-                gl::FALSE,
-                0,
-            );
-            gl::VertexArrayAttribBinding(self.vao_slice, ATTR_COL, BINDING_COL);
-            // TODO: gl::VertexArrayBindingDivisor(self.vao_slice, BINDING_COL, 6);
-
-            let (vertices, colors) = self.gather_tetrahedra_attributes();
-
-            // Any tetrahedral slice can have at most 6 vertices (a quadrilateral, 2 triangles).
-            let max_vertices_per_slice = 6;
-
-            let vertices_size = mem::size_of::<Vector4<f32>>()
-                * Tetrahedron::get_number_of_vertices()
-                * self.tetrahedra.len() as usize;
-            let colors_size = mem::size_of::<Vector4<f32>>()
-                * max_vertices_per_slice
-                * self.tetrahedra.len() as usize;
-
-            // The VBO that will be associated with the vertex attribute #1, which does not change
-            // throughout the lifetime of the program (thus, we use the flag `STATIC_DRAW` below).
-            gl::CreateBuffers(1, &mut self.buffer_slice_colors);
-            gl::NamedBufferData(
-                self.buffer_slice_colors,
-                colors_size as isize,
-                colors.as_ptr() as *const GLvoid,
-                gl::STATIC_DRAW,
-            );
-
-            // The buffer that will be bound at index #0 and read from.
-            gl::CreateBuffers(1, &mut self.buffer_tetrahedra);
-            gl::NamedBufferData(
-                self.buffer_tetrahedra,
-                vertices_size as isize,
-                vertices.as_ptr() as *const GLvoid,
-                gl::STATIC_DRAW,
-            );
-
-            // Items that will be written to on the GPU (more or less every frame).
-            // ...
-
-            // The buffer of slice vertices that will be written to whenever the slicing hyperplane moves.
-            let mut alloc_size =
-                mem::size_of::<Vector4<f32>>() * max_vertices_per_slice * self.tetrahedra.len();
-            gl::CreateBuffers(1, &mut self.buffer_slice_vertices);
-            gl::NamedBufferData(
-                self.buffer_slice_vertices,
-                alloc_size as isize,
-                ptr::null() as *const GLvoid,
-                gl::STREAM_DRAW,
-            );
-
-            // The buffer of draw commands that will be filled out by the compute shader dispatch.
-            alloc_size = mem::size_of::<DrawCommand>() * self.tetrahedra.len();
-            gl::CreateBuffers(1, &mut self.buffer_indirect_commands);
-            gl::NamedBufferData(
-                self.buffer_indirect_commands,
-                alloc_size as isize,
-                ptr::null() as *const GLvoid,
-                gl::STREAM_DRAW,
-            );
-
-            // Set up SSBO bind points.
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 0, self.buffer_tetrahedra);
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 1, self.buffer_slice_vertices);
-            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 2, self.buffer_indirect_commands);
-
-            // Setup vertex attribute bindings.
-            gl::VertexArrayVertexBuffer(
-                self.vao_slice,
-                BINDING_POS,
-                self.buffer_slice_vertices,
-                0,
-                mem::size_of::<Vector4<f32>>() as i32,
-            );
-            gl::VertexArrayVertexBuffer(
-                self.vao_slice,
-                BINDING_COL,
-                self.buffer_slice_colors,
-                0,
-                mem::size_of::<Vector4<f32>>() as i32,
-            );
-
-            let mut local_size: [i32; 3] = [0; 3];
-            gl::GetProgramiv(
-                self.compute.get_id(),
-                gl::COMPUTE_WORK_GROUP_SIZE,
-                local_size.as_mut_ptr(),
-            );
-        }
-    }
-
-    /// Initializes all OpenGL objects for rendering wireframes of all of the
-    /// tetrahedra that make up this polychoron, which are embedded in 4-dimensions.
-    fn init_tetrahedra_objects(&mut self) {
-        unsafe {
-            // First, create the vertex array object.
-            gl::CreateVertexArrays(1, &mut self.vao_tetrahedra);
-
-            // Create the element buffer that will hold all of the edge indices for rendering
-            // wireframes of all of the tetrahedra that make up this polychoron.
-            let indices = self.gather_tetrahedra_indices();
-            let indices_size = (indices.len() * mem::size_of::<u32>()) as GLsizeiptr;
-
-            gl::CreateBuffers(1, &mut self.ebo_tetrahedra);
-            gl::NamedBufferData(
-                self.ebo_tetrahedra,
-                indices_size,
-                indices.as_ptr() as *const GLvoid,
-                gl::DYNAMIC_DRAW,
-            );
-
-            gl::EnableVertexArrayAttrib(self.vao_tetrahedra, 0);
-            gl::VertexArrayAttribFormat(
-                self.vao_tetrahedra,
-                0,
-                self.def.components_per_vertex as i32,
-                gl::FLOAT,
-                gl::FALSE,
-                0,
-            );
-            gl::VertexArrayAttribBinding(self.vao_tetrahedra, 0, 0);
-
-            // Setup vertex attribute bindings: notice that we use the same VBO from above that
-            // holds all of the vertices of the tetrahedra that make up this polychoron.
-            gl::VertexArrayVertexBuffer(
-                self.vao_tetrahedra,
-                0,
-                self.buffer_tetrahedra,
-                0,
-                (mem::size_of::<f32>() * self.def.components_per_vertex as usize) as i32,
-            );
-
-            // Bind the EBO to the VAO.
-            gl::VertexArrayElementBuffer(self.vao_tetrahedra, self.ebo_tetrahedra);
-        }
-    }
-
-    /// Initializes all OpenGL objects for rendering the edges of this polychoron.
-    fn init_edges_objects(&mut self) {
-        unsafe {
-            // First, create the vertex array object.
-            gl::CreateVertexArrays(1, &mut self.vao_edges);
-
-            // Set up attribute #0: positions (for now, we ignore colors).
-            const ATTR_POS: u32 = 0;
-            const BINDING_POS: u32 = 0;
-            gl::EnableVertexArrayAttrib(self.vao_edges, ATTR_POS);
-            gl::VertexArrayAttribFormat(
-                self.vao_edges,
-                ATTR_POS,
-                self.def.components_per_vertex as i32,
-                gl::FLOAT,
-                gl::FALSE,
-                0,
-            );
-            gl::VertexArrayAttribBinding(self.vao_edges, ATTR_POS, BINDING_POS);
-
-            // Create the vertex buffer that will hold all of the polychoron's unique vertices.
-            let vertices_size =
-                (self.vertices.len() * mem::size_of::<Vector4<f32>>()) as GLsizeiptr;
-
-            gl::CreateBuffers(1, &mut self.vbo_edges);
-            gl::NamedBufferData(
-                self.vbo_edges,
-                vertices_size as isize,
-                self.vertices.as_ptr() as *const GLvoid,
-                gl::STATIC_DRAW,
-            );
-
-            // Setup vertex attribute bindings.
-            gl::VertexArrayVertexBuffer(
-                self.vao_edges,
-                BINDING_POS,
-                self.vbo_edges,
-                0,
-                mem::size_of::<Vector4<f32>>() as i32,
-            );
-
-            // Create the element buffer that will hold all of the edge indices for rendering
-            // a wireframe of this polychoron.
-            let edges_size = (self.edges.len() * mem::size_of::<u32>()) as GLsizeiptr;
-
-            gl::CreateBuffers(1, &mut self.ebo_edges);
-            gl::NamedBufferData(
-                self.ebo_edges,
-                edges_size,
-                self.edges.as_ptr() as *const GLvoid,
-                gl::STATIC_DRAW,
-            );
-
-            // Bind the EBO to the VAO.
-            gl::VertexArrayElementBuffer(self.vao_edges, self.ebo_edges);
-        }
+        self.cell_faces = cell_faces;
+        self.cell_edges = cell_edges;
+        self.cell_centroids = cell_centroids;
     }
 }
@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use cgmath::{InnerSpace, Vector4, Zero};
+
+use hull::ConvexHull;
+use mesh::Mesh;
+use utilities;
+
+/// The golden ratio.
+const PHI: f32 = 1.618_034;
+
+/// How close points need to be to a common hyperplane in order to be considered as lying
+/// on the same cell, relative to the circumradius of the points. The points that are
+/// produced by each operator are derived from the vertices of another mesh, so they have
+/// accumulated more rounding error than the hull's default tolerance allows for.
+const RELATIVE_TOLERANCE: f32 = 1.0e-4;
+
+/// Builds a new mesh from the convex hull of `points`, or returns `None` if there aren't
+/// enough points (in general position) to enclose any hypervolume, or if the topology of
+/// the hull isn't valid (see `Mesh::validate(...)`).
+fn from_points(points: &[Vector4<f32>]) -> Option<Mesh> {
+    if points.len() < 5 {
+        return None;
+    }
+
+    let center = utilities::average(points, &Vector4::zero());
+    let radius = points
+        .iter()
+        .map(|point| (point - center).magnitude())
+        .fold(0.0, f32::max);
+
+    let mesh = Mesh::new(ConvexHull::with_tolerance(
+        points,
+        radius * RELATIVE_TOLERANCE,
+    ));
+    if mesh.get_number_of_vertices() == 0 || mesh.validate().is_err() {
+        return None;
+    }
+
+    Some(mesh)
+}
+
+/// Returns the pairs of vertices that make up each of the edges of `mesh`.
+fn get_edge_vertices(mesh: &Mesh) -> Vec<(Vector4<f32>, Vector4<f32>)> {
+    (0..mesh.get_number_of_edges())
+        .map(|i| mesh.get_vertices_for_edge(i as u32))
+        .collect()
+}
+
+/// Returns the average number of vertices per face of `mesh`, or `None` if the mesh
+/// doesn't have any faces.
+fn get_average_face_size(mesh: &Mesh) -> Option<f32> {
+    let faces = mesh.get_faces();
+    if faces.is_empty() {
+        return None;
+    }
+
+    Some(faces.iter().map(|face| face.len() as f32).sum::<f32>() / faces.len() as f32)
+}
+
+/// Returns the rectification of a convex `mesh`, whose vertices are the midpoints of the
+/// edges of the original. For example, rectifying the 600-cell produces a polychoron with
+/// 600 octahedral and 120 icosahedral cells.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Rectification_(geometry)`
+pub fn rectify(mesh: &Mesh) -> Option<Mesh> {
+    let points = get_edge_vertices(mesh)
+        .iter()
+        .map(|(a, b)| (a + b) * 0.5)
+        .collect::<Vec<_>>();
+
+    from_points(&points)
+}
+
+/// Returns the truncation of a convex `mesh`, where each vertex is cut off at `depth`
+/// (as a fraction of the edge length) along each edge that meets there. This should be
+/// less than `0.5`, which would be the same as the rectification.
+pub fn truncate_by(mesh: &Mesh, depth: f32) -> Option<Mesh> {
+    let points = get_edge_vertices(mesh)
+        .iter()
+        .flat_map(|(a, b)| vec![a + (b - a) * depth, b + (a - b) * depth])
+        .collect::<Vec<_>>();
+
+    from_points(&points)
+}
+
+/// Returns the truncation of a convex `mesh`, where the depth of each cut is chosen so
+/// that the faces of the original become regular `2p`-gons (assuming that they were
+/// regular `p`-gons to begin with). For a regular polychoron, the result is uniform.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Truncation_(geometry)`
+pub fn truncate(mesh: &Mesh) -> Option<Mesh> {
+    // Cutting a corner (with interior angle `π - 2π/p`) at a distance `x` along both of
+    // its edges produces a new edge of length `2x·cos(π/p)`, which should match the length
+    // of what remains of each original edge, `1 - 2x`.
+    let depth = match get_average_face_size(mesh) {
+        Some(p) => 1.0 / (2.0 * (1.0 + (PI / p).cos())),
+        None => 1.0 / 3.0,
+    };
+
+    truncate_by(mesh, depth)
+}
+
+/// Returns the bitruncation of a convex `mesh`, which has a vertex for each pair of an
+/// edge and a face that contains it. Each vertex lies inside of the face, on the line
+/// between the midpoint of the edge and the centroid of the face. For example,
+/// bitruncating the 8-cell produces 8 truncated octahedra and 16 truncated tetrahedra.
+///
+/// The distance along this line is chosen so that all of the edges have the same length
+/// for a regular polychoron: see the comments below.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Bitruncation`
+pub fn bitruncate(mesh: &Mesh) -> Option<Mesh> {
+    let complex = mesh.get_boundary_complex()?;
+
+    let get_edge_midpoint = |edge: u32| {
+        let [a, b] = complex.get_edge(edge);
+        (complex.get_vertex(a) + complex.get_vertex(b)) * 0.5
+    };
+    let get_face_centroid = |face: u32| {
+        let vertices = complex
+            .get_face(face)
+            .iter()
+            .map(|vertex| complex.get_vertex(*vertex))
+            .collect::<Vec<_>>();
+        utilities::average(&vertices, &Vector4::zero())
+    };
+
+    // Each new vertex is `s` of the way from an edge's midpoint to a face's centroid. Two
+    // neighboring vertices in the same face are separated by `2(1 - s)·a·sin(π/p)`, where
+    // `a` is the face's apothem. Two neighboring vertices around the same edge (in faces
+    // that meet at a dihedral angle `δ` within some cell) are separated by `2s·a·sin(δ/2)`.
+    // Equating these gives `s`: the sines are averaged over the entire mesh.
+    let mut face_sine = 0.0;
+    let mut dihedral_sine = 0.0;
+    let mut samples = 0;
+    for edge in 0..complex.get_number_of_edges() as u32 {
+        let midpoint = get_edge_midpoint(edge);
+        let faces = complex.get_faces_around_edge(edge);
+
+        for cell in complex.get_cells_around_edge(edge) {
+            let cell_faces = complex.get_faces_of_cell(cell);
+            let pair = faces
+                .iter()
+                .filter(|face| cell_faces.contains(face))
+                .collect::<Vec<_>>();
+            if pair.len() != 2 {
+                continue;
+            }
+
+            let u = (get_face_centroid(*pair[0]) - midpoint).normalize();
+            let v = (get_face_centroid(*pair[1]) - midpoint).normalize();
+            let dihedral = utilities::saturate_between(u.dot(v), -1.0, 1.0).acos();
+
+            dihedral_sine += (dihedral * 0.5).sin();
+            face_sine += (PI / complex.get_face(*pair[0]).len() as f32).sin();
+            samples += 1;
+        }
+    }
+    if samples == 0 {
+        return None;
+    }
+    let s = face_sine / (face_sine + dihedral_sine);
+
+    let mut points = Vec::new();
+    for face in 0..complex.get_number_of_faces() as u32 {
+        let centroid = get_face_centroid(face);
+        for edge in complex.get_edges_of_face(face) {
+            let midpoint = get_edge_midpoint(*edge);
+            points.push(midpoint + (centroid - midpoint) * s);
+        }
+    }
+
+    from_points(&points)
+}
+
+/// Returns the alternation of a convex `mesh`, which keeps every other vertex (so that
+/// no two of the remaining vertices were joined by an edge). This requires the vertices
+/// and edges of the mesh to form a bipartite graph: otherwise, this returns `None`.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Alternation_(geometry)`
+pub fn alternate(mesh: &Mesh) -> Option<Mesh> {
+    let mut neighbors = vec![Vec::new(); mesh.get_number_of_vertices()];
+    for pair in mesh.get_edges().chunks(2) {
+        neighbors[pair[0] as usize].push(pair[1] as usize);
+        neighbors[pair[1] as usize].push(pair[0] as usize);
+    }
+
+    // Color the vertices with a breadth-first search, starting from the first vertex of
+    // each connected component.
+    let mut colors: Vec<Option<bool>> = vec![None; neighbors.len()];
+    for start in 0..neighbors.len() {
+        if colors[start].is_some() {
+            continue;
+        }
+        colors[start] = Some(true);
+
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        while let Some(current) = queue.pop_front() {
+            let color = colors[current]?;
+            for neighbor in neighbors[current].iter() {
+                match colors[*neighbor] {
+                    Some(other) if other == color => return None,
+                    Some(_) => (),
+                    None => {
+                        colors[*neighbor] = Some(!color);
+                        queue.push_back(*neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    let points = mesh
+        .get_vertices()
+        .iter()
+        .zip(colors.iter())
+        .filter(|(_, color)| **color == Some(true))
+        .map(|(vertex, _)| *vertex)
+        .collect::<Vec<_>>();
+
+    from_points(&points)
+}
+
+/// Returns the snub of a convex `mesh`: the alternation of a truncation whose cuts
+/// divide each edge in the golden ratio. For the 24-cell, this is the uniform snub
+/// 24-cell (with 24 icosahedral and 120 tetrahedral cells). Returns `None` if the
+/// truncation can't be alternated.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Snub_24-cell`
+pub fn snub(mesh: &Mesh) -> Option<Mesh> {
+    alternate(&truncate_by(mesh, 1.0 / (PHI * PHI))?)
+}
+
+/// Returns the compound of a convex `mesh` and its dual (see `Mesh::get_dual(...)`),
+/// which is scaled to have the same circumradius as the original. Both are assumed to
+/// be centered at the origin.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Polytope_compound`
+pub fn compound_with_dual(mesh: &Mesh) -> Option<Mesh> {
+    let dual = mesh.get_dual()?;
+    let dual = dual.scaled(mesh.get_circumradius() / dual.get_circumradius());
+
+    Some(mesh.compound(&dual))
+}
@@ -1,13 +1,14 @@
 use std::f32::consts::FRAC_1_SQRT_2;
 
-use cgmath::{self, Vector4};
+use cgmath::Vector4;
 
+use hull;
 use hyperplane::Hyperplane;
-use polychora::Polychoron::Cell24Rectified;
 
 /// A struct that describes a regular polychoron (4-polytope).
 ///
 /// See: `https://en.wikipedia.org/wiki/4-polytope`
+#[derive(Copy, Clone, Debug)]
 pub struct Definition {
     /// The number of components (i.e. x, y, z, w, ...) per vertex: this should always be 4
     pub components_per_vertex: u32,
@@ -28,6 +29,85 @@ pub struct Definition {
     pub cells: u32,
 }
 
+impl Definition {
+    /// Builds a definition from the `faces` (each of which is a list of vertex indices)
+    /// and `cells` (each of which is a list of face indices) of a polytope. Any of the
+    /// per-face or per-cell counts will be 0 if they vary across the polytope.
+    pub fn from_topology(faces: &[Vec<u32>], cells: &[Vec<u32>]) -> Definition {
+        let vertices_per_face = faces
+            .iter()
+            .map(|face| face.len() as u32)
+            .collect::<Vec<_>>();
+        let faces_per_cell = cells
+            .iter()
+            .map(|cell| cell.len() as u32)
+            .collect::<Vec<_>>();
+        let vertices_per_cell = cells
+            .iter()
+            .map(|cell| {
+                let mut vertices = cell
+                    .iter()
+                    .flat_map(|face| faces[*face as usize].iter())
+                    .collect::<Vec<_>>();
+                vertices.sort();
+                vertices.dedup();
+                vertices.len() as u32
+            })
+            .collect::<Vec<_>>();
+
+        Definition {
+            components_per_vertex: 4,
+            vertices_per_edge: 2,
+            vertices_per_face: uniform_or_zero(&vertices_per_face),
+            vertices_per_cell: uniform_or_zero(&vertices_per_cell),
+            faces_per_cell: uniform_or_zero(&faces_per_cell),
+            cells: cells.len() as u32,
+        }
+    }
+}
+
+/// Returns the first entry in `values` if every entry is equal to it and 0 otherwise.
+fn uniform_or_zero(values: &[u32]) -> u32 {
+    match values.first() {
+        Some(first) if values.iter().all(|value| value == first) => *first,
+        _ => 0,
+    }
+}
+
+/// A trait representing anything that can describe the boundary of a 4-dimensional
+/// polytope: a list of vertices, plus the edges and faces that connect them. This is
+/// everything that a `Mesh` needs in order to tetrahedralize (and ultimately slice) a
+/// shape.
+pub trait Polytope {
+    /// Returns a topological definition corresponding to this polytope.
+    fn get_definition(&self) -> Definition;
+
+    /// Returns a list of the unique vertices that make up this polytope.
+    fn get_vertices(&self) -> Vec<Vector4<f32>>;
+
+    /// Returns a list of the edge indices that correspond to this polytope. Indices
+    /// should *always* be considered in pairs, as each edge consists of 2 vertices.
+    fn get_edges(&self) -> Vec<u32>;
+
+    /// Returns a list of faces, where each face is a list of indices into the array
+    /// returned by `get_vertices(...)`. Faces do not all need to have the same number
+    /// of vertices.
+    fn get_faces(&self) -> Vec<Vec<u32>>;
+
+    /// The H-representation of a polytope is the list of hyperplanes whose intersection
+    /// produces the desired shape. Together, these hyperplanes form a "boundary" for the
+    /// shape. We use this representation in order to determine which faces belong to
+    /// each of the cells that form the polytope's surface.
+    ///
+    /// By default, the hyperplanes are found by computing the convex hull of this
+    /// polytope's vertices, so this works for any convex polytope.
+    ///
+    /// See: `https://en.wikipedia.org/wiki/Convex_polytope#Intersection_of_half-spaces`
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        hull::get_bounding_hyperplanes(&self.get_vertices())
+    }
+}
+
 /// A polychoron is a polytope that exists in 4-dimensions. It is the 4-dimensional
 /// analog of a polyhedron. It is made up of vertices, edges, faces, and cells. Each
 /// cell is itself a polyhedra.
@@ -35,7 +115,7 @@ pub struct Definition {
 /// Shape files are (largely) from Paul Bourke's website (see this project's `README`).
 /// They are formatted as follows:
 ///
-/// ```
+/// ```text
 /// number_of_vertices
 /// x0 y0 z0 w0
 /// x1 y1 z1 w1
@@ -56,6 +136,9 @@ pub struct Definition {
 /// Reference: `http://paulbourke.net/geometry/hyperspace/`
 #[derive(Copy, Clone)]
 pub enum Polychoron {
+    /// A polytope with 5 tetrahedral cells: the "4-simplex"
+    Cell5,
+
     /// A polytope with 8 cubic cells: the "tesseract"
     Cell8,
 
@@ -65,12 +148,6 @@ pub enum Polychoron {
     /// A polytope with 24 octahedral cells
     Cell24,
 
-    /// Another construction of the `Cell24` via the rectification of the `Cell16`: this is only
-    /// used to calculate the H-representation of the "regular" `Cell24` (even though the 24-cell
-    /// is self-dual, a rotation needs to happen before its vertices can be used as cell centers
-    /// to find hyperplane boundaries)
-    Cell24Rectified,
-
     /// A polytope with 120 dodecahedral cells
     Cell120,
 
@@ -78,10 +155,18 @@ pub enum Polychoron {
     Cell600,
 }
 
-impl Polychoron {
+impl Polytope for Polychoron {
     /// Returns a topological definition corresponding to this polychoron.
-    pub fn get_definition(&self) -> Definition {
+    fn get_definition(&self) -> Definition {
         match *self {
+            Polychoron::Cell5 => Definition {
+                components_per_vertex: 4,
+                vertices_per_edge: 2,
+                vertices_per_face: 3,
+                vertices_per_cell: 4,
+                faces_per_cell: 4,
+                cells: 5,
+            },
             Polychoron::Cell8 => Definition {
                 components_per_vertex: 4,
                 vertices_per_edge: 2,
@@ -98,7 +183,7 @@ impl Polychoron {
                 faces_per_cell: 4,
                 cells: 16,
             },
-            Polychoron::Cell24 | Polychoron::Cell24Rectified => Definition {
+            Polychoron::Cell24 => Definition {
                 components_per_vertex: 4,
                 vertices_per_edge: 2,
                 vertices_per_face: 3,
@@ -131,8 +216,15 @@ impl Polychoron {
     /// The Y and Z (2nd and 3rd) coordinates for the vertices of the 600-cell had to
     /// be permuted (switched) from Paul Bourke's original VEF file...not entirely sure
     /// why, yet.
-    pub fn get_vertices(&self) -> Vec<Vector4<f32>> {
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
         match *self {
+            Polychoron::Cell5 => vec![
+                Vector4::new(0.25, 0.322749, 0.456435, 0.790569),
+                Vector4::new(0.25, 0.322749, 0.456435, -0.790569),
+                Vector4::new(0.25, 0.322749, -0.912871, 0.0),
+                Vector4::new(0.25, -0.968246, 0.0, 0.0),
+                Vector4::new(-1.0, 0.0, 0.0, 0.0),
+            ],
             Polychoron::Cell8 => vec![
                 Vector4::new(-0.5, -0.5, -0.5, -0.5),
                 Vector4::new(-0.5, -0.5, -0.5, 0.5),
@@ -188,32 +280,6 @@ impl Polychoron {
                 Vector4::new(0.5, 0.5, 0.5, 0.5),
             ],
 
-            Polychoron::Cell24Rectified => vec![
-                Vector4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0),
-                Vector4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0),
-                Vector4::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0, 0.0),
-                Vector4::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0, 0.0),
-                Vector4::new(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0),
-                Vector4::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0),
-                Vector4::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0),
-                Vector4::new(0.0, -FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0),
-                Vector4::new(0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2),
-                Vector4::new(0.0, 0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
-                Vector4::new(0.0, 0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
-                Vector4::new(0.0, 0.0, -FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
-                Vector4::new(0.0, FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2),
-                Vector4::new(0.0, -FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2),
-                Vector4::new(0.0, FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2),
-                Vector4::new(0.0, -FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2),
-                Vector4::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0),
-                Vector4::new(-FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0),
-                Vector4::new(FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2, 0.0),
-                Vector4::new(-FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2, 0.0),
-                Vector4::new(FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2),
-                Vector4::new(-FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2),
-                Vector4::new(FRAC_1_SQRT_2, 0.0, 0.0, -FRAC_1_SQRT_2),
-                Vector4::new(-FRAC_1_SQRT_2, 0.0, 0.0, -FRAC_1_SQRT_2),
-            ],
             Polychoron::Cell120 => vec![
                 Vector4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0), // TODO: use std::f64::consts::FRAC_1_SQRT_2 ?
                 Vector4::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0, 0.0),
@@ -945,8 +1011,9 @@ impl Polychoron {
     /// should *always* be considered in pairs, as each edge of any polychoron consists
     /// of 2 vertices. Each of these pairs of indices can be used to index into the array
     /// returned by `get_vertices(...)` to obtain the two vertices that make up that edge.
-    pub fn get_edges(&self) -> Vec<u32> {
+    fn get_edges(&self) -> Vec<u32> {
         match *self {
+            Polychoron::Cell5 => vec![0, 1, 0, 2, 0, 3, 0, 4, 1, 2, 1, 3, 1, 4, 2, 3, 2, 4, 3, 4],
             Polychoron::Cell8 => vec![
                 0, 2, 2, 3, 3, 1, 1, 0, 0, 4, 4, 5, 5, 1, 4, 6, 6, 2, 0, 8, 8, 9, 9, 1, 8, 10, 10,
                 2, 8, 12, 12, 4, 6, 7, 7, 5, 7, 3, 10, 11, 11, 3, 9, 11, 9, 13, 13, 5, 12, 13, 12,
@@ -967,7 +1034,6 @@ impl Polychoron {
                 22, 22, 4, 20, 5, 21, 5, 14, 22, 22, 5, 22, 6, 22, 23, 23, 4, 21, 23, 23, 5, 19,
                 23, 23, 6, 23, 7, 15, 23,
             ],
-            Polychoron::Cell24Rectified => vec![unimplemented!()],
             Polychoron::Cell120 => vec![
                 305, 401, 25, 585, 401, 585, 25, 153, 153, 305, 307, 403, 27, 587, 403, 587, 27,
                 155, 155, 307, 17, 401, 17, 403, 305, 307, 137, 425, 139, 427, 137, 139, 321, 427,
@@ -1188,14 +1254,16 @@ impl Polychoron {
         }
     }
 
-    /// Returns a list of the face indices that correspond to this polychoron. Each
-    /// index corresponds to an edge. For example, if a polychoron has cells with triangular
-    /// faces, then the indices returned by this function should be considered in
-    /// groups of 3, where each of these 3 indices corresponds to one of that triangle's
-    /// edges. In other words, each set of 3 indices can be used to index into the array
-    /// returned by `get_edges(...)` to obtain the 3 edges that make up that face.
-    pub fn get_faces(&self) -> Vec<u32> {
-        match *self {
+    /// Returns a list of the faces that correspond to this polychoron. For example, if a
+    /// polychoron has cells with triangular faces, then each face will contain 3 indices,
+    /// each of which can be used to index into the array returned by `get_vertices(...)`
+    /// to obtain one of that triangle's vertices.
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        let faces = match *self {
+            Polychoron::Cell5 => vec![
+                0, 1, 2, 0, 1, 3, 0, 1, 4, 0, 2, 3, 0, 2, 4, 0, 3, 4, 1, 2, 3, 1, 2, 4, 1, 3, 4, 2,
+                3, 4,
+            ],
             Polychoron::Cell8 => vec![
                 0, 2, 3, 1, 0, 4, 5, 1, 0, 4, 6, 2, 0, 8, 9, 1, 0, 8, 10, 2, 0, 8, 12, 4, 4, 6, 7,
                 5, 2, 6, 7, 3, 1, 5, 7, 3, 2, 10, 11, 3, 1, 9, 11, 3, 1, 9, 13, 5, 8, 10, 11, 9, 8,
@@ -1223,7 +1291,6 @@ impl Polychoron {
                 21, 23, 5, 22, 23, 5, 21, 23, 4, 19, 23, 6, 22, 23, 6, 19, 23, 7, 21, 23, 7, 19,
                 23, 5, 15, 23, 6, 15, 23, 7, 15, 23,
             ],
-            Polychoron::Cell24Rectified => vec![unimplemented!()],
             Polychoron::Cell120 => vec![
                 25, 153, 305, 401, 585, 27, 155, 307, 403, 587, 17, 305, 307, 401, 403, 137, 139,
                 321, 425, 427, 25, 137, 425, 553, 585, 27, 139, 427, 555, 587, 17, 385, 401, 553,
@@ -1618,64 +1685,19 @@ impl Polychoron {
                 90, 113, 86, 94, 118, 84, 92, 116, 87, 95, 119, 80, 88, 96, 81, 89, 97, 82, 90, 98,
                 83, 91, 99, 84, 92, 100, 85, 93, 101, 86, 94, 102, 87, 95, 103,
             ],
-        }
-    }
-
-    /// The H-representation of a regular polychoron is the list of hyperplanes whose
-    /// intersection produces the desired shape. Together, these hyperplanes form
-    /// a "boundary" for the shape. We use this representation in order to determine
-    /// which faces belong to each of the cells that form the polychoron's surface.
-    ///
-    /// See: `https://en.wikipedia.org/wiki/Convex_polytope#Intersection_of_half-spaces`
-    pub fn get_h_representation(&self) -> Vec<Hyperplane> {
-        // By definition, a polychoron's dual has a vertex at the center of each of the
-        // "original" polychoron's cells. We can exploit this information in order to find
-        // the distance from each of the bounding hyperplanes in this polychoron's H-representation
-        // to the origin.
-        let displacement = match *self {
-            Polychoron::Cell8 => 0.5,
-            Polychoron::Cell16 => 0.5,
-            Polychoron::Cell24 => FRAC_1_SQRT_2,
-            Polychoron::Cell24Rectified => FRAC_1_SQRT_2,
-            Polychoron::Cell120 => -0.925615, // See email snippet below as to where this comes from.
-            Polychoron::Cell600 => -0.925615,
         };
 
-        // Notes from "T", the author of `http://eusebeia.dyndns.org/`:
-        //
-        // "One way to determine the correct [hyperplane] scale `d` is to leave the constant
-        // term in the hyperplane equation as an unknown and plug in a vertex
-        // known to lie on that hyperplane, then solve for the constant. Since the
-        // 120-cell and 600-cell are regular polytopes, once you find one constant
-        // all the other constants should be equal (by symmetry).
-        //
-        // Fortunately, if you're using the 600-cell coordinates from my website, 8
-        // of them are aligned with the coordinate axes, so you can easily
-        // determine the coordinates for at least 8 pairs of dodecahedra in the
-        // 120-cell easily: for example, if you choose `<2,0,0,0>` as the normal,
-        // then clearly the vertices that lie on the hyperplane perpendicular to it
-        // must have the largest first coordinate (since otherwise there would be
-        // cells containing points that rise above that hyperplane, implying that
-        // the 120-cell is non-convex, which is a contradiction). So, you can sort
-        // the vertices of the 120-cell by their first coordinate in descending
-        // order, and pick the ones with the largest value (there should be 20 of
-        // them if you did it correctly). Then, pick any of them to plug it into the
-        // equation to solve for the constant term."
-
-        // Calculate the midpoint of any edge, find its distance to the origin and use this
-        // as the displacement?
-
-        let mut bounding_hyperplanes = Vec::new();
-
-        for vertex in self.get_dual().get_vertices().iter() {
-            // Note that the normal vectors provided here will be automatically normalized
-            // in the constructor.
-            bounding_hyperplanes.push(Hyperplane::new(*vertex, displacement));
-        }
-
-        bounding_hyperplanes
+        // All of the faces of a regular polychoron have the same number of vertices, so
+        // the table above is stored "flat" and split into groups here.
+        let vertices_per_face = self.get_definition().vertices_per_face as usize;
+        faces
+            .chunks(vertices_per_face)
+            .map(|face| face.to_vec())
+            .collect()
     }
+}
 
+impl Polychoron {
     /// The V-representation of a polychoron is simple its list of vertices.
     pub fn get_v_representation(&self) -> Vec<Vector4<f32>> {
         self.get_vertices()
@@ -1683,13 +1705,14 @@ impl Polychoron {
 
     /// Returns the dual of the regular polychoron, which is itself another regular
     /// polychoron with a vertex at the center of each cell of the original polychoron.
-    /// A polychoron is self-dual if it is its own dual (such as the 24-cell).
+    /// A polychoron is self-dual if it is its own dual (such as the 24-cell). See
+    /// `Mesh::get_dual(...)` for building the dual of any convex mesh.
     pub fn get_dual(&self) -> Polychoron {
         match *self {
+            Polychoron::Cell5 => Polychoron::Cell5,
             Polychoron::Cell8 => Polychoron::Cell16,
             Polychoron::Cell16 => Polychoron::Cell8,
-            Polychoron::Cell24 => Polychoron::Cell24Rectified,
-            Polychoron::Cell24Rectified => Polychoron::Cell24, // See: `http://eusebeia.dyndns.org/4d/24-cell`
+            Polychoron::Cell24 => Polychoron::Cell24, // See: `http://eusebeia.dyndns.org/4d/24-cell`
             Polychoron::Cell120 => Polychoron::Cell600,
             Polychoron::Cell600 => Polychoron::Cell120,
         }
@@ -0,0 +1,323 @@
+use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+use cgmath::{InnerSpace, Vector3, Vector4, Zero};
+
+use math;
+use tetrahedron::Tetrahedron;
+use utilities;
+
+/// The 6 tetrahedra that fill a unit cube, all of which share the cube's main diagonal
+/// (the Kuhn / Freudenthal triangulation). Each entry is the order in which the axes
+/// are stepped along when walking from corner `<0, 0, 0>` to corner `<1, 1, 1>`.
+/// Since neighboring cubes are split the same way, the tetrahedra of a grid of cubes
+/// always meet face-to-face.
+const KUHN_PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+/// Returns the number of segments along a parameter that spans `fraction` of a full
+/// turn, where `resolution` is the number of segments around a full turn.
+fn segments(resolution: u32, fraction: f32) -> u32 {
+    ((resolution as f32 * fraction).round() as u32).max(1)
+}
+
+/// Tessellates the parametric 3-manifold `f`, which maps the unit cube `[0, 1]^3` into
+/// 4-dimensions, into tetrahedra. The unit cube is divided into a grid of
+/// `resolution[0] x resolution[1] x resolution[2]` smaller cubes ("patches"), each of
+/// which produces 6 tetrahedra.
+///
+/// Each patch is assigned its own cell index (starting at `first_cell_index`), and all
+/// of its tetrahedra share the centroid of the patch. Tetrahedra that collapse to
+/// lower-dimensional shapes under `f` (for example, around the poles of a sphere) are
+/// discarded.
+fn tessellate<F>(resolution: [u32; 3], first_cell_index: u32, f: F) -> Vec<Tetrahedron>
+where
+    F: Fn(Vector3<f32>) -> Vector4<f32>,
+{
+    let mut tetrahedra = Vec::new();
+    let step = Vector3::new(
+        1.0 / resolution[0] as f32,
+        1.0 / resolution[1] as f32,
+        1.0 / resolution[2] as f32,
+    );
+
+    for k in 0..resolution[2] {
+        for j in 0..resolution[1] {
+            for i in 0..resolution[0] {
+                let cell_index = first_cell_index + i + resolution[0] * (j + resolution[1] * k);
+                let origin = Vector3::new(i as f32 * step.x, j as f32 * step.y, k as f32 * step.z);
+
+                // Maps a corner of the current patch (i.e. `<0, 1, 1>`) into 4-dimensions.
+                let corner = |offset: [u32; 3]| {
+                    f(Vector3::new(
+                        origin.x + offset[0] as f32 * step.x,
+                        origin.y + offset[1] as f32 * step.y,
+                        origin.z + offset[2] as f32 * step.z,
+                    ))
+                };
+
+                let mut corners = Vec::new();
+                for z in 0..2 {
+                    for y in 0..2 {
+                        for x in 0..2 {
+                            corners.push(corner([x, y, z]));
+                        }
+                    }
+                }
+                let cell_centroid = utilities::average(&corners, &Vector4::zero());
+
+                for permutation in KUHN_PERMUTATIONS.iter() {
+                    let mut offset = [0; 3];
+                    let mut vertices = [corner(offset); 4];
+                    for (vertex, axis) in vertices.iter_mut().skip(1).zip(permutation.iter()) {
+                        offset[*axis] = 1;
+                        *vertex = corner(offset);
+                    }
+
+                    if !is_degenerate(&vertices) {
+                        tetrahedra.push(Tetrahedron::new(vertices, cell_index, cell_centroid));
+                    }
+                }
+            }
+        }
+    }
+
+    tetrahedra
+}
+
+/// Returns `true` if the tetrahedron formed by `vertices` has (almost) no volume.
+fn is_degenerate(vertices: &[Vector4<f32>; 4]) -> bool {
+    // Check for vertices that (almost) coincide first: the points that a collapsed edge
+    // maps to aren't exactly equal (for example, `sin(π)` isn't exactly zero), and the
+    // relative test below doesn't notice a single very short edge.
+    let mut lengths = Vec::new();
+    for i in 0..vertices.len() {
+        for j in (i + 1)..vertices.len() {
+            lengths.push((vertices[j] - vertices[i]).magnitude2());
+        }
+    }
+    let shortest = lengths.iter().cloned().fold(f32::MAX, f32::min);
+    let longest = lengths.iter().cloned().fold(0.0, f32::max);
+    if shortest <= 1.0e-10 * longest {
+        return true;
+    }
+
+    let a = vertices[1] - vertices[0];
+    let b = vertices[2] - vertices[0];
+    let c = vertices[3] - vertices[0];
+
+    // The squared volume of the parallelepiped spanned by `a`, `b`, and `c` is the
+    // determinant of their Gram matrix.
+    let (aa, ab, ac) = (a.dot(a), a.dot(b), a.dot(c));
+    let (bb, bc, cc) = (b.dot(b), b.dot(c), c.dot(c));
+    let gram = aa * (bb * cc - bc * bc) - ab * (ab * cc - bc * ac) + ac * (ab * bc - bb * ac);
+
+    gram <= 1.0e-7 * (aa * bb * cc)
+}
+
+/// Returns the number of cells (patches) produced by `tessellate(...)` for a grid with the
+/// given `resolution`.
+fn number_of_patches(resolution: [u32; 3]) -> u32 {
+    resolution[0] * resolution[1] * resolution[2]
+}
+
+/// Returns a tessellation of the 3-sphere (the boundary of the 4-dimensional ball) with
+/// the given `radius`, centered at the origin. `resolution` is the number of segments
+/// around each great circle.
+pub fn hypersphere(radius: f32, resolution: u32) -> Vec<Tetrahedron> {
+    let grid = [
+        segments(resolution, 0.5),
+        segments(resolution, 0.5),
+        segments(resolution, 1.0),
+    ];
+
+    tessellate(grid, 0, |p| {
+        math::hyperspherical_to_cartesian(radius, p.x * PI, p.y * PI, p.z * 2.0 * PI)
+    })
+}
+
+/// Returns a tessellation of the surface of a spherinder: the Cartesian product of a
+/// 3-dimensional ball (with the given `radius`) and a line segment along the `w`-axis
+/// (with the given `height`). Its surface consists of two balls (at either end of the
+/// segment) joined by a spherical "tube".
+///
+/// Reference: `https://en.wikipedia.org/wiki/Spherinder`
+pub fn spherinder(radius: f32, height: f32, resolution: u32) -> Vec<Tetrahedron> {
+    let half_height = height * 0.5;
+    let mut tetrahedra = Vec::new();
+    let mut cell_index = 0;
+
+    // The two end caps are 3-dimensional balls.
+    let ball = [
+        segments(resolution, 0.25),
+        segments(resolution, 0.5),
+        segments(resolution, 1.0),
+    ];
+    for w in [-half_height, half_height].iter() {
+        tetrahedra.extend(tessellate(ball, cell_index, |p| {
+            let mut point = math::hyperspherical_to_cartesian(
+                p.x * radius,
+                FRAC_PI_2,
+                p.y * PI,
+                p.z * 2.0 * PI,
+            );
+            point.w = *w;
+            point
+        }));
+        cell_index += number_of_patches(ball);
+    }
+
+    // The side is a sphere that is swept along the `w`-axis.
+    let tube = [
+        segments(resolution, 0.25),
+        segments(resolution, 0.5),
+        segments(resolution, 1.0),
+    ];
+    tetrahedra.extend(tessellate(tube, cell_index, |p| {
+        let mut point =
+            math::hyperspherical_to_cartesian(radius, FRAC_PI_2, p.y * PI, p.z * 2.0 * PI);
+        point.w = -half_height + p.x * height;
+        point
+    }));
+
+    tetrahedra
+}
+
+/// Returns a tessellation of the surface of a cubinder: the Cartesian product of a
+/// square in the `xy`-plane (with side length `2 * size`) and a disk in the `zw`-plane
+/// (with the given `radius`). Its surface consists of 4 solid cylinders (one for each
+/// side of the square) and a "square torus" (the square times the disk's boundary circle).
+///
+/// Reference: `https://en.wikipedia.org/wiki/Cubinder`
+pub fn cubinder(size: f32, radius: f32, resolution: u32) -> Vec<Tetrahedron> {
+    let mut tetrahedra = Vec::new();
+    let mut cell_index = 0;
+
+    // The sides of the square. Each side runs in the same direction as the parameter of
+    // the square torus below that it borders, so that the two are split into tetrahedra
+    // along the same diagonals.
+    let sides = [
+        (
+            Vector4::new(-size, -size, 0.0, 0.0),
+            Vector4::new(size, -size, 0.0, 0.0),
+        ),
+        (
+            Vector4::new(size, -size, 0.0, 0.0),
+            Vector4::new(size, size, 0.0, 0.0),
+        ),
+        (
+            Vector4::new(-size, size, 0.0, 0.0),
+            Vector4::new(size, size, 0.0, 0.0),
+        ),
+        (
+            Vector4::new(-size, -size, 0.0, 0.0),
+            Vector4::new(-size, size, 0.0, 0.0),
+        ),
+    ];
+
+    // Each side of the square, times the disk, is a solid cylinder.
+    let cylinder = [
+        segments(resolution, 0.25),
+        segments(resolution, 0.25),
+        segments(resolution, 1.0),
+    ];
+    for (start, end) in sides.iter() {
+        tetrahedra.extend(tessellate(cylinder, cell_index, |p| {
+            let angle = p.z * 2.0 * PI;
+            start
+                + (end - start) * p.x
+                + Vector4::new(0.0, 0.0, angle.cos(), angle.sin()) * (p.y * radius)
+        }));
+        cell_index += number_of_patches(cylinder);
+    }
+
+    // The square, times the circle that bounds the disk.
+    let torus = [
+        segments(resolution, 0.25),
+        segments(resolution, 0.25),
+        segments(resolution, 1.0),
+    ];
+    tetrahedra.extend(tessellate(torus, cell_index, |p| {
+        let angle = p.z * 2.0 * PI;
+        Vector4::new(
+            (p.x * 2.0 - 1.0) * size,
+            (p.y * 2.0 - 1.0) * size,
+            radius * angle.cos(),
+            radius * angle.sin(),
+        )
+    }));
+
+    tetrahedra
+}
+
+/// Returns a tessellation of the surface of a duocylinder: the Cartesian product of a
+/// disk in the `xy`-plane (with radius `radius_xy`) and a disk in the `zw`-plane (with
+/// radius `radius_zw`). Its surface consists of two solid tori, which meet along a flat
+/// (Clifford) torus.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Duocylinder`
+pub fn duocylinder(radius_xy: f32, radius_zw: f32, resolution: u32) -> Vec<Tetrahedron> {
+    let grid = [
+        segments(resolution, 1.0),
+        segments(resolution, 0.25),
+        segments(resolution, 1.0),
+    ];
+
+    // The circle bounding the `xy` disk, times the `zw` disk.
+    let mut tetrahedra = tessellate(grid, 0, |p| {
+        let (alpha, beta) = (p.x * 2.0 * PI, p.z * 2.0 * PI);
+        Vector4::new(
+            radius_xy * alpha.cos(),
+            radius_xy * alpha.sin(),
+            p.y * radius_zw * beta.cos(),
+            p.y * radius_zw * beta.sin(),
+        )
+    });
+
+    // The `xy` disk, times the circle bounding the `zw` disk.
+    tetrahedra.extend(tessellate(grid, number_of_patches(grid), |p| {
+        let (alpha, beta) = (p.x * 2.0 * PI, p.z * 2.0 * PI);
+        Vector4::new(
+            p.y * radius_xy * alpha.cos(),
+            p.y * radius_xy * alpha.sin(),
+            radius_zw * beta.cos(),
+            radius_zw * beta.sin(),
+        )
+    }));
+
+    tetrahedra
+}
+
+/// Returns a tessellation of a "thickened" Clifford torus. The Clifford torus itself is
+/// the flat, 2-dimensional torus `(cos(α), sin(α), cos(β), sin(β)) / √2` that lies on
+/// the unit 3-sphere, so it has no volume and cannot be sliced. Instead, we tessellate
+/// the boundary of the set of points within `thickness` of it, which is a 3-torus.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Clifford_torus`
+pub fn clifford_torus(thickness: f32, resolution: u32) -> Vec<Tetrahedron> {
+    let grid = [
+        segments(resolution, 1.0),
+        segments(resolution, 1.0),
+        segments(resolution, 0.5),
+    ];
+
+    tessellate(grid, 0, |p| {
+        let (alpha, beta, gamma) = (p.x * 2.0 * PI, p.y * 2.0 * PI, p.z * 2.0 * PI);
+
+        // The two circles that make up the torus are offset along their own (radial)
+        // directions, which together span the plane normal to the torus.
+        let radius_xy = FRAC_1_SQRT_2 + thickness * gamma.cos();
+        let radius_zw = FRAC_1_SQRT_2 + thickness * gamma.sin();
+        Vector4::new(
+            radius_xy * alpha.cos(),
+            radius_xy * alpha.sin(),
+            radius_zw * beta.cos(),
+            radius_zw * beta.sin(),
+        )
+    })
+}
@@ -0,0 +1,482 @@
+use std::collections::BTreeSet;
+use std::f32::consts::PI;
+
+use cgmath::{InnerSpace, Vector3, Vector4};
+
+use constants;
+use hyperplane::Hyperplane;
+use math;
+use polychora::{Definition, Polytope};
+
+/// A convex polyhedron in 3-dimensions, which can be used as the base of a `Prism`.
+#[derive(Clone, Debug)]
+pub struct Polyhedron {
+    /// The vertices of the polyhedron
+    vertices: Vec<Vector3<f32>>,
+
+    /// The faces of the polyhedron, each of which is a list of vertex indices (in order)
+    faces: Vec<Vec<u32>>,
+}
+
+impl Polyhedron {
+    /// Constructs the convex polyhedron whose vertices are `vertices`. Every point must
+    /// be a vertex of the polyhedron (i.e. no point can lie inside of the convex hull of
+    /// the others).
+    ///
+    /// The faces are found by brute force: any plane through 3 of the vertices that has
+    /// all of the other vertices on one side of it contains a face. This is `O(n^4)`, but
+    /// the polyhedra that we care about are small.
+    pub fn from_vertices(vertices: Vec<Vector3<f32>>) -> Polyhedron {
+        let mut seen = BTreeSet::new();
+        let mut faces = Vec::new();
+
+        for a in 0..vertices.len() {
+            for b in (a + 1)..vertices.len() {
+                for c in (b + 1)..vertices.len() {
+                    let normal = (vertices[b] - vertices[a]).cross(vertices[c] - vertices[a]);
+                    if normal.magnitude() <= constants::EPSILON {
+                        continue;
+                    }
+                    let normal = normal.normalize();
+
+                    let distances = vertices
+                        .iter()
+                        .map(|vertex| normal.dot(vertex - vertices[a]))
+                        .collect::<Vec<_>>();
+                    let above = distances.iter().any(|d| *d > constants::EPSILON);
+                    let below = distances.iter().any(|d| *d < -constants::EPSILON);
+                    if above && below {
+                        continue;
+                    }
+
+                    let face = (0..vertices.len() as u32)
+                        .filter(|index| distances[*index as usize].abs() <= constants::EPSILON)
+                        .collect::<Vec<_>>();
+                    if !seen.insert(face.clone()) {
+                        continue;
+                    }
+
+                    // Sort the vertices of the face so that they form a closed polygon.
+                    let points = face
+                        .iter()
+                        .map(|index| vertices[*index as usize].extend(0.0))
+                        .collect::<Vec<_>>();
+                    faces.push(
+                        math::sort_indices_on_plane(&points)
+                            .iter()
+                            .map(|k| face[*k])
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        Polyhedron { vertices, faces }
+    }
+
+    /// Returns a regular tetrahedron with unit circumradius.
+    pub fn tetrahedron() -> Polyhedron {
+        Polyhedron::from_unnormalized(&[
+            [1.0, 1.0, 1.0],
+            [1.0, -1.0, -1.0],
+            [-1.0, 1.0, -1.0],
+            [-1.0, -1.0, 1.0],
+        ])
+    }
+
+    /// Returns a cube with unit circumradius.
+    pub fn cube() -> Polyhedron {
+        let mut coordinates = Vec::new();
+        for x in [-1.0, 1.0].iter() {
+            for y in [-1.0, 1.0].iter() {
+                for z in [-1.0, 1.0].iter() {
+                    coordinates.push([*x, *y, *z]);
+                }
+            }
+        }
+        Polyhedron::from_unnormalized(&coordinates)
+    }
+
+    /// Returns a regular octahedron with unit circumradius.
+    pub fn octahedron() -> Polyhedron {
+        Polyhedron::from_unnormalized(&[
+            [1.0, 0.0, 0.0],
+            [-1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0],
+        ])
+    }
+
+    /// Returns a regular dodecahedron with unit circumradius.
+    ///
+    /// Reference: `https://en.wikipedia.org/wiki/Regular_dodecahedron#Cartesian_coordinates`
+    pub fn dodecahedron() -> Polyhedron {
+        let phi = (1.0 + 5.0f32.sqrt()) * 0.5;
+        let mut coordinates = Vec::new();
+        for a in [-1.0, 1.0].iter() {
+            for b in [-1.0, 1.0].iter() {
+                for c in [-1.0, 1.0].iter() {
+                    coordinates.push([*a, *b, *c]);
+                }
+                coordinates.push([0.0, a / phi, b * phi]);
+                coordinates.push([a / phi, b * phi, 0.0]);
+                coordinates.push([a * phi, 0.0, b / phi]);
+            }
+        }
+        Polyhedron::from_unnormalized(&coordinates)
+    }
+
+    /// Returns a regular icosahedron with unit circumradius.
+    ///
+    /// Reference: `https://en.wikipedia.org/wiki/Regular_icosahedron#Cartesian_coordinates`
+    pub fn icosahedron() -> Polyhedron {
+        let phi = (1.0 + 5.0f32.sqrt()) * 0.5;
+        let mut coordinates = Vec::new();
+        for a in [-1.0, 1.0].iter() {
+            for b in [-1.0, 1.0].iter() {
+                coordinates.push([0.0, *a, b * phi]);
+                coordinates.push([*a, b * phi, 0.0]);
+                coordinates.push([a * phi, 0.0, *b]);
+            }
+        }
+        Polyhedron::from_unnormalized(&coordinates)
+    }
+
+    /// Constructs a polyhedron from a list of coordinates, after scaling them to have
+    /// unit length.
+    fn from_unnormalized(coordinates: &[[f32; 3]]) -> Polyhedron {
+        Polyhedron::from_vertices(
+            coordinates
+                .iter()
+                .map(|c| Vector3::from(*c).normalize())
+                .collect(),
+        )
+    }
+
+    /// Returns the vertices of this polyhedron.
+    pub fn get_vertices(&self) -> Vec<Vector3<f32>> {
+        self.vertices.clone()
+    }
+
+    /// Returns the faces of this polyhedron, each of which is a list of vertex indices.
+    pub fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    /// Returns the edges of this polyhedron, as pairs of vertex indices: these are
+    /// the sides of its faces.
+    pub fn get_edges(&self) -> Vec<u32> {
+        let mut edges = BTreeSet::new();
+        for face in self.faces.iter() {
+            for (i, a) in face.iter().enumerate() {
+                let b = face[(i + 1) % face.len()];
+                edges.insert((*a.min(&b), *a.max(&b)));
+            }
+        }
+
+        edges.iter().flat_map(|(a, b)| vec![*a, *b]).collect()
+    }
+
+    /// Returns the length of the shortest edge of this polyhedron. A prism over this
+    /// polyhedron with this height will be uniform (all of its edges will have the same
+    /// length) if the polyhedron itself is.
+    pub fn get_edge_length(&self) -> f32 {
+        self.get_edges()
+            .chunks(2)
+            .map(|edge| {
+                (self.vertices[edge[1] as usize] - self.vertices[edge[0] as usize]).magnitude()
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Returns the centroid of this polyhedron's vertices, which is guaranteed to lie
+    /// inside of the polyhedron.
+    fn get_centroid(&self) -> Vector3<f32> {
+        self.vertices
+            .iter()
+            .fold(Vector3::new(0.0, 0.0, 0.0), |acc, v| acc + v)
+            / self.vertices.len() as f32
+    }
+}
+
+/// A prism over a 3-dimensional polyhedron: the polyhedron is "extruded" along the
+/// `w`-axis, so that it forms the two end cells of the prism. Each face of the polyhedron
+/// becomes a (3-dimensional) prism that joins the two end cells.
+///
+/// For example, a prism over a cube is an 8-cell.
+pub struct Prism {
+    /// The vertices of the prism: the first half are on the `w = -height / 2` end cell
+    vertices: Vec<Vector4<f32>>,
+
+    /// The edges of the prism, as pairs of vertex indices
+    edges: Vec<u32>,
+
+    /// The faces of the prism, each of which is a list of vertex indices (in order)
+    faces: Vec<Vec<u32>>,
+
+    /// The cells of the prism, each of which is a list of face indices
+    cells: Vec<Vec<u32>>,
+
+    /// The hyperplanes that bound each of the cells of the prism
+    h_representation: Vec<Hyperplane>,
+}
+
+impl Prism {
+    /// Constructs a prism over `base` that extends from `w = -height / 2` to
+    /// `w = height / 2`.
+    pub fn new(base: &Polyhedron, height: f32) -> Prism {
+        let offset = base.vertices.len() as u32;
+        let half_height = height * 0.5;
+
+        let mut vertices = base
+            .vertices
+            .iter()
+            .map(|v| v.extend(-half_height))
+            .collect::<Vec<_>>();
+        vertices.extend(base.vertices.iter().map(|v| v.extend(half_height)));
+
+        // Each edge of the base appears on both end cells, and each vertex of the base
+        // is connected to its copy.
+        let base_edges = base.get_edges();
+        let mut edges = base_edges.clone();
+        edges.extend(base_edges.iter().map(|index| index + offset));
+        for index in 0..offset {
+            edges.extend_from_slice(&[index, index + offset]);
+        }
+
+        // Each face of the base appears on both end cells, and each edge of the base
+        // is extruded into a quad.
+        let number_of_faces = base.faces.len() as u32;
+        let mut faces = base.faces.clone();
+        faces.extend(
+            base.faces
+                .iter()
+                .map(|face| face.iter().map(|index| index + offset).collect::<Vec<_>>()),
+        );
+
+        let mut quads = Vec::new();
+        for edge in base_edges.chunks(2) {
+            quads.push((edge[0], edge[1]));
+            faces.push(vec![edge[0], edge[1], edge[1] + offset, edge[0] + offset]);
+        }
+
+        let mut cells = vec![
+            (0..number_of_faces).collect::<Vec<_>>(),
+            (number_of_faces..number_of_faces * 2).collect::<Vec<_>>(),
+        ];
+        let mut h_representation = vec![
+            Hyperplane::new(Vector4::new(0.0, 0.0, 0.0, -1.0), -half_height),
+            Hyperplane::new(Vector4::new(0.0, 0.0, 0.0, 1.0), -half_height),
+        ];
+
+        // Each face of the base is extruded into a prism cell, which is bounded by the
+        // face's two copies and the quads along the face's edges.
+        let centroid = base.get_centroid();
+        for (face_index, face) in base.faces.iter().enumerate() {
+            let mut cell = vec![face_index as u32, face_index as u32 + number_of_faces];
+            for (quad_index, (a, b)) in quads.iter().enumerate() {
+                if face.contains(a) && face.contains(b) {
+                    cell.push(number_of_faces * 2 + quad_index as u32);
+                }
+            }
+            cells.push(cell);
+
+            let p0 = base.vertices[face[0] as usize];
+            let mut normal = (base.vertices[face[1] as usize] - p0)
+                .cross(base.vertices[face[2] as usize] - p0)
+                .normalize();
+            if normal.dot(p0 - centroid) < 0.0 {
+                normal = -normal;
+            }
+            h_representation.push(Hyperplane::new(normal.extend(0.0), -normal.dot(p0)));
+        }
+
+        Prism {
+            vertices,
+            edges,
+            faces,
+            cells,
+            h_representation,
+        }
+    }
+
+    /// Returns a list of the cells of this prism, where each cell is a list of indices
+    /// into the array returned by `get_faces(...)`. The `i`th cell lies in the `i`th
+    /// hyperplane of the prism's H-representation.
+    pub fn get_cells(&self) -> Vec<Vec<u32>> {
+        self.cells.clone()
+    }
+}
+
+impl Polytope for Prism {
+    fn get_definition(&self) -> Definition {
+        Definition::from_topology(&self.faces, &self.cells)
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        self.vertices.clone()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        self.edges.clone()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
+
+/// A `p,q`-duoprism: the Cartesian product of a regular `p`-gon (in the `xy`-plane) and
+/// a regular `q`-gon (in the `zw`-plane). Its cells are `p` `q`-gonal prisms and `q`
+/// `p`-gonal prisms.
+///
+/// For example, the `4,4`-duoprism is an 8-cell.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Duoprism`
+pub struct Duoprism {
+    /// The vertices of the duoprism: vertex `i * q + j` is the product of the `i`th vertex
+    /// of the `p`-gon and the `j`th vertex of the `q`-gon
+    vertices: Vec<Vector4<f32>>,
+
+    /// The edges of the duoprism, as pairs of vertex indices
+    edges: Vec<u32>,
+
+    /// The faces of the duoprism, each of which is a list of vertex indices (in order)
+    faces: Vec<Vec<u32>>,
+
+    /// The cells of the duoprism, each of which is a list of face indices
+    cells: Vec<Vec<u32>>,
+
+    /// The hyperplanes that bound each of the cells of the duoprism
+    h_representation: Vec<Hyperplane>,
+}
+
+impl Duoprism {
+    /// Constructs the uniform `p,q`-duoprism (i.e. all of its edges have the same length)
+    /// with unit circumradius.
+    pub fn new(p: u32, q: u32) -> Duoprism {
+        assert!(p >= 3 && q >= 3, "Both polygons must have at least 3 sides");
+
+        // Pick the radii of the two polygons so that their sides have the same length and
+        // every vertex has unit length.
+        let (sin_p, sin_q) = ((PI / p as f32).sin(), (PI / q as f32).sin());
+        let scale = 1.0 / (sin_p * sin_p + sin_q * sin_q).sqrt();
+        let (radius_p, radius_q) = (sin_q * scale, sin_p * scale);
+
+        let index = |i: u32, j: u32| (i % p) * q + (j % q);
+
+        let mut vertices = Vec::new();
+        for i in 0..p {
+            let theta = 2.0 * PI * i as f32 / p as f32;
+            for j in 0..q {
+                let phi = 2.0 * PI * j as f32 / q as f32;
+                vertices.push(Vector4::new(
+                    radius_p * theta.cos(),
+                    radius_p * theta.sin(),
+                    radius_q * phi.cos(),
+                    radius_q * phi.sin(),
+                ));
+            }
+        }
+
+        let mut edges = Vec::new();
+        for i in 0..p {
+            for j in 0..q {
+                edges.extend_from_slice(&[index(i, j), index(i + 1, j)]);
+                edges.extend_from_slice(&[index(i, j), index(i, j + 1)]);
+            }
+        }
+
+        // The faces are, in order: `q` `p`-gons, `p` `q`-gons, then `p * q` squares.
+        let mut faces = Vec::new();
+        for j in 0..q {
+            faces.push((0..p).map(|i| index(i, j)).collect::<Vec<_>>());
+        }
+        for i in 0..p {
+            faces.push((0..q).map(|j| index(i, j)).collect::<Vec<_>>());
+        }
+        for i in 0..p {
+            for j in 0..q {
+                faces.push(vec![
+                    index(i, j),
+                    index(i + 1, j),
+                    index(i + 1, j + 1),
+                    index(i, j + 1),
+                ]);
+            }
+        }
+        let square = |i: u32, j: u32| p + q + (i % p) * q + (j % q);
+
+        let mut cells = Vec::new();
+        let mut h_representation = Vec::new();
+
+        // Each side of the `p`-gon sweeps out a `q`-gonal prism.
+        for i in 0..p {
+            let mut cell = vec![q + i, q + (i + 1) % p];
+            cell.extend((0..q).map(|j| square(i, j)));
+            cells.push(cell);
+
+            let theta = PI * (2 * i + 1) as f32 / p as f32;
+            h_representation.push(Hyperplane::new(
+                Vector4::new(theta.cos(), theta.sin(), 0.0, 0.0),
+                -radius_p * (PI / p as f32).cos(),
+            ));
+        }
+
+        // Each side of the `q`-gon sweeps out a `p`-gonal prism.
+        for j in 0..q {
+            let mut cell = vec![j, (j + 1) % q];
+            cell.extend((0..p).map(|i| square(i, j)));
+            cells.push(cell);
+
+            let phi = PI * (2 * j + 1) as f32 / q as f32;
+            h_representation.push(Hyperplane::new(
+                Vector4::new(0.0, 0.0, phi.cos(), phi.sin()),
+                -radius_q * (PI / q as f32).cos(),
+            ));
+        }
+
+        Duoprism {
+            vertices,
+            edges,
+            faces,
+            cells,
+            h_representation,
+        }
+    }
+
+    /// Returns a list of the cells of this duoprism, where each cell is a list of indices
+    /// into the array returned by `get_faces(...)`. The `i`th cell lies in the `i`th
+    /// hyperplane of the duoprism's H-representation.
+    pub fn get_cells(&self) -> Vec<Vec<u32>> {
+        self.cells.clone()
+    }
+}
+
+impl Polytope for Duoprism {
+    fn get_definition(&self) -> Definition {
+        Definition::from_topology(&self.faces, &self.cells)
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        self.vertices.clone()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        self.edges.clone()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
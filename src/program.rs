@@ -2,12 +2,13 @@ use gl;
 use gl::types::*;
 
 use cgmath;
-use cgmath::{Array, Matrix, Matrix4, Vector2, Vector3, Vector4};
+use cgmath::{Array, Matrix};
 
 use std::ffi::CString;
 use std::ptr;
 use std::str;
 
+#[allow(dead_code)]
 pub struct UniformEntry {
     name: String,
     location: GLint,
@@ -41,10 +42,7 @@ impl Program {
             if status != (gl::TRUE as GLint) {
                 let mut len = 0;
                 gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut len);
-                let mut buffer = Vec::with_capacity(len as usize);
-
-                // Subtract 1 to skip the trailing null character.
-                buffer.set_len((len as usize) - 1);
+                let mut buffer = vec![0u8; len as usize];
 
                 gl::GetShaderInfoLog(
                     shader,
@@ -53,9 +51,10 @@ impl Program {
                     buffer.as_mut_ptr() as *mut GLchar,
                 );
 
-                let error = String::from_utf8(buffer)
-                    .ok()
-                    .expect("ShaderInfoLog not valid utf8");
+                // Drop the trailing null character.
+                buffer.truncate((len as usize) - 1);
+
+                let error = String::from_utf8(buffer).expect("ShaderInfoLog not valid utf8");
                 return Err(error);
             }
         }
@@ -77,10 +76,7 @@ impl Program {
             if status != (gl::TRUE as GLint) {
                 let mut len: GLint = 0;
                 gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut len);
-                let mut buffer = Vec::with_capacity(len as usize);
-
-                // Subtract 1 to skip the trailing null character.
-                buffer.set_len((len as usize) - 1);
+                let mut buffer = vec![0u8; len as usize];
 
                 gl::GetProgramInfoLog(
                     program,
@@ -88,11 +84,12 @@ impl Program {
                     ptr::null_mut(),
                     buffer.as_mut_ptr() as *mut GLchar,
                 );
+
+                // Drop the trailing null character.
+                buffer.truncate((len as usize) - 1);
                 gl::DeleteShader(cs);
 
-                let error = String::from_utf8(buffer)
-                    .ok()
-                    .expect("ProgramInfoLog not valid utf8");
+                let error = String::from_utf8(buffer).expect("ProgramInfoLog not valid utf8");
                 return Err(error);
             }
 
@@ -115,10 +112,7 @@ impl Program {
             if status != (gl::TRUE as GLint) {
                 let mut len: GLint = 0;
                 gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut len);
-                let mut buffer = Vec::with_capacity(len as usize);
-
-                // Subtract 1 to skip the trailing null character.
-                buffer.set_len((len as usize) - 1);
+                let mut buffer = vec![0u8; len as usize];
 
                 gl::GetProgramInfoLog(
                     program,
@@ -126,12 +120,13 @@ impl Program {
                     ptr::null_mut(),
                     buffer.as_mut_ptr() as *mut GLchar,
                 );
+
+                // Drop the trailing null character.
+                buffer.truncate((len as usize) - 1);
                 gl::DeleteShader(fs);
                 gl::DeleteShader(vs);
 
-                let error = String::from_utf8(buffer)
-                    .ok()
-                    .expect("ProgramInfoLog not valid utf8");
+                let error = String::from_utf8(buffer).expect("ProgramInfoLog not valid utf8");
                 return Err(error);
             }
 
@@ -148,28 +143,26 @@ impl Program {
         match (compile_vs_res, compile_fs_res) {
             (Ok(vs_id), Ok(fs_id)) => {
                 // Make sure that linking the shader program was successful.
-                if let Ok(id) = Program::link_two_stage_program(vs_id, fs_id) {
-                    // If everything went ok, return the shader program.
-                    return Some(Program { id });
-                } else {
-                    return None;
-                }
+                // If everything went ok, return the shader program.
+                Program::link_two_stage_program(vs_id, fs_id)
+                    .ok()
+                    .map(|id| Program { id })
             }
             // Both shader stages resulted in an error.
             (Err(vs_err), Err(fs_err)) => {
                 println!("{}", vs_err);
                 println!("{}", fs_err);
-                return None;
+                None
             }
             // The vertex shader resulted in an error.
             (Err(vs_err), Ok(_)) => {
                 println!("{}", vs_err);
-                return None;
+                None
             }
             // The fragment shader resulted in an error.
             (Ok(_), Err(fs_err)) => {
                 println!("{}", fs_err);
-                return None;
+                None
             }
         }
     }
@@ -179,16 +172,12 @@ impl Program {
         let compile_cs_res = Program::compile_shader(&cs_src, gl::COMPUTE_SHADER);
 
         match compile_cs_res {
-            Ok(cs_id) => {
-                if let Ok(id) = Program::link_single_stage_program(cs_id) {
-                    return Some(Program { id });
-                } else {
-                    return None;
-                }
-            }
+            Ok(cs_id) => Program::link_single_stage_program(cs_id)
+                .ok()
+                .map(|id| Program { id }),
             Err(cs_err) => {
                 println!("{}", cs_err);
-                return None;
+                None
             }
         }
     }
@@ -0,0 +1,856 @@
+use std::mem;
+use std::path::Path;
+use std::ptr;
+
+use cgmath::Vector4;
+use gl;
+use gl::types::*;
+
+use hyperplane::Hyperplane;
+use mesh::Mesh;
+use program::Program;
+use schlegel::SchlegelDiagram;
+use stereographic::StereographicProjection;
+use tetrahedron::Tetrahedron;
+use unfold::Net;
+use utilities;
+
+/// A struct representing an entry in the indirect draw buffer.
+#[repr(C)]
+struct DrawCommand {
+    count: u32,
+    instance_count: u32,
+    first: u32,
+    base_instance: u32,
+}
+
+/// The GPU-side objects (buffers, VAOs, and the slicing compute shader) that are used
+/// to draw a `Mesh`.
+pub struct Renderer {
+    /// The mesh that is drawn by this renderer.
+    mesh: Mesh,
+
+    /// The compute shader that is used to compute 3-dimensional slices of the mesh.
+    compute: Program,
+
+    /// The vertex array object (VAO) that is used for drawing a 3D slice of the mesh.
+    vao_slice: u32,
+
+    /// A GPU-side buffer that contains all of the tetrahedra that make up the mesh.
+    buffer_tetrahedra: u32,
+
+    /// A GPU-side buffer that contains all of the colors used to render 3-dimensional slices of the mesh.
+    buffer_slice_colors: u32,
+
+    /// A GPU-side buffer that contains all of the vertices that make up the active 3-dimensional cross-section of the mesh.
+    buffer_slice_vertices: u32,
+
+    /// A GPU-side buffer that will be filled with indirect drawing commands via the `compute` program.
+    buffer_indirect_commands: u32,
+
+    /// The VAO that is used for drawing all of the tetrahedra that make up the mesh.
+    vao_tetrahedra: u32,
+
+    /// The EBO that is used for drawing all of the edges of the tetrahedra that make up the mesh.
+    ebo_tetrahedra: u32,
+
+    /// The VAO that is used for drawing the wireframe of the mesh.
+    vao_edges: u32,
+
+    /// A GPU-side buffer that contains all of the unique vertices that make up the mesh.
+    vbo_edges: u32,
+
+    /// The EBO that is used for drawing the wireframe of the mesh.
+    ebo_edges: u32,
+
+    /// The VAO that is used for drawing the polygonal cross-sections of the mesh's cells.
+    vao_cell_slices: u32,
+
+    /// A GPU-side buffer that contains the vertices of the polygonal cross-sections of the mesh's cells.
+    buffer_cell_slice_vertices: u32,
+
+    /// A GPU-side buffer that contains the colors of the polygonal cross-sections of the mesh's cells.
+    buffer_cell_slice_colors: u32,
+
+    /// The index of the first vertex of each polygon in `buffer_cell_slice_vertices`.
+    cell_slice_firsts: Vec<i32>,
+
+    /// The number of vertices in each polygon in `buffer_cell_slice_vertices`.
+    cell_slice_counts: Vec<i32>,
+
+    /// The net of the mesh, or `None` if the mesh doesn't have any cells to unfold.
+    net: Option<Net>,
+
+    /// The Schlegel diagram of the mesh, or `None` if the mesh doesn't have any cells to
+    /// project through (or no diagram has been requested yet).
+    schlegel: Option<SchlegelDiagram>,
+
+    /// The VAO that is used for drawing the edges of each cell of the mesh separately, i.e.
+    /// for the net or Schlegel diagram of the mesh.
+    vao_cells: u32,
+
+    /// A GPU-side buffer that contains the (transformed) vertices of each cell of the mesh.
+    buffer_cell_vertices: u32,
+
+    /// A GPU-side buffer that contains the colors of each cell of the mesh.
+    buffer_cell_colors: u32,
+
+    /// The EBO that is used for drawing the edges of each cell of the mesh.
+    ebo_cells: u32,
+
+    /// The number of indices in `ebo_cells`.
+    cell_index_count: usize,
+
+    /// The VAO that is used for drawing the (curved) edges of the stereographic projection
+    /// of the mesh.
+    vao_curves: u32,
+
+    /// A GPU-side buffer that contains the line segments that make up each projected edge.
+    buffer_curve_vertices: u32,
+
+    /// The number of vertices in `buffer_curve_vertices`.
+    curve_vertex_count: usize,
+}
+
+impl Renderer {
+    /// Creates all of the GPU-side objects that are needed to draw `mesh`. This requires
+    /// a current OpenGL context.
+    pub fn new(mesh: Mesh) -> Renderer {
+        let compute = utilities::load_file_as_string(Path::new("shaders/compute_slice.glsl"));
+
+        let net = mesh.get_net();
+        let mut renderer = Renderer {
+            mesh,
+            compute: Program::single_stage(compute).unwrap(),
+            vao_slice: 0,
+            buffer_tetrahedra: 0,
+            buffer_slice_colors: 0,
+            buffer_slice_vertices: 0,
+            buffer_indirect_commands: 0,
+            vao_tetrahedra: 0,
+            ebo_tetrahedra: 0,
+            vao_edges: 0,
+            vbo_edges: 0,
+            ebo_edges: 0,
+            vao_cell_slices: 0,
+            buffer_cell_slice_vertices: 0,
+            buffer_cell_slice_colors: 0,
+            cell_slice_firsts: Vec::new(),
+            cell_slice_counts: Vec::new(),
+            net,
+            schlegel: None,
+            vao_cells: 0,
+            buffer_cell_vertices: 0,
+            buffer_cell_colors: 0,
+            ebo_cells: 0,
+            cell_index_count: 0,
+            vao_curves: 0,
+            buffer_curve_vertices: 0,
+            curve_vertex_count: 0,
+        };
+
+        renderer.init_render_objects();
+        renderer
+    }
+
+    /// Returns the mesh that is drawn by this renderer.
+    pub fn get_mesh(&self) -> &Mesh {
+        &self.mesh
+    }
+
+    /// Returns the mesh that is drawn by this renderer, i.e. in order to change its
+    /// transform.
+    pub fn get_mesh_mut(&mut self) -> &mut Mesh {
+        &mut self.mesh
+    }
+
+    /// Slice this renderer's mesh with a 4-dimensional `hyperplane`, taking into account
+    /// the mesh's current transform.
+    pub fn slice(&mut self, hyperplane: &Hyperplane) {
+        self.compute.bind();
+        self.compute
+            .uniform_4f("u_hyperplane_normal", &hyperplane.normal);
+        self.compute
+            .uniform_1f("u_hyperplane_displacement", hyperplane.displacement);
+
+        self.compute
+            .uniform_matrix_4f("u_transform", self.mesh.get_transform());
+
+        unsafe {
+            // Bind buffers for read / write.
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 0, self.buffer_tetrahedra);
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 1, self.buffer_slice_vertices);
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 2, self.buffer_indirect_commands);
+
+            let dispatch = (self.mesh.get_tetrahedra().len() as f32 / 128.0).ceil();
+            gl::DispatchCompute(dispatch as u32, 1, 1);
+
+            // Barrier against subsequent SSBO reads and indirect drawing commands.
+            gl::MemoryBarrier(gl::SHADER_STORAGE_BARRIER_BIT | gl::COMMAND_BARRIER_BIT);
+        }
+
+        self.compute.unbind();
+    }
+
+    /// Slice each of the cells of this renderer's mesh with a 4-dimensional `hyperplane`
+    /// on the CPU (see `Mesh::slice_cells(...)`), taking into account the mesh's current
+    /// transform. Unlike `slice(...)`, this produces the true polygonal cross-section of
+    /// each cell, rather than the triangles that come from its tetrahedra.
+    pub fn slice_cells(&mut self, hyperplane: &Hyperplane) {
+        let mut vertices = Vec::new();
+        let mut colors = Vec::new();
+        self.cell_slice_firsts.clear();
+        self.cell_slice_counts.clear();
+
+        for cell_slice in self.mesh.slice_cells(hyperplane) {
+            self.cell_slice_firsts.push(vertices.len() as i32);
+            self.cell_slice_counts
+                .push(cell_slice.vertices.len() as i32);
+
+            vertices.extend_from_slice(&cell_slice.vertices);
+            for _ in 0..cell_slice.vertices.len() {
+                colors.push(cell_slice.cell_centroid);
+            }
+        }
+
+        unsafe {
+            // The number of vertices changes along with the hyperplane, so the buffers
+            // are re-allocated each time.
+            gl::NamedBufferData(
+                self.buffer_cell_slice_vertices,
+                (vertices.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STREAM_DRAW,
+            );
+            gl::NamedBufferData(
+                self.buffer_cell_slice_colors,
+                (colors.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                colors.as_ptr() as *const GLvoid,
+                gl::STREAM_DRAW,
+            );
+        }
+    }
+
+    /// Draws the polygonal cross-sections that were computed by the last call to
+    /// `slice_cells(...)`. If `outline` is `true`, only the boundary of each polygon is
+    /// drawn, without the diagonals that would come from triangulating it.
+    pub fn draw_cell_slices(&self, outline: bool) {
+        let primitive = if outline {
+            gl::LINE_LOOP
+        } else {
+            gl::TRIANGLE_FAN
+        };
+
+        unsafe {
+            gl::BindVertexArray(self.vao_cell_slices);
+            gl::MultiDrawArrays(
+                primitive,
+                self.cell_slice_firsts.as_ptr(),
+                self.cell_slice_counts.as_ptr(),
+                self.cell_slice_counts.len() as i32,
+            );
+        }
+    }
+
+    /// Unfolds the net of this renderer's mesh by `amount`, where `0` is the original
+    /// polychoron and `1` is the flat, 3-dimensional net (see `Net::unfold(...)`). This
+    /// does nothing if the mesh doesn't have any cells.
+    pub fn unfold(&mut self, amount: f32) {
+        if let Some(ref net) = self.net {
+            let vertices = net.unfold(amount).concat();
+
+            unsafe {
+                gl::NamedBufferSubData(
+                    self.buffer_cell_vertices,
+                    0,
+                    (vertices.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                    vertices.as_ptr() as *const GLvoid,
+                );
+            }
+        }
+    }
+
+    /// Projects this renderer's mesh through its `cell`th cell to form a Schlegel diagram,
+    /// where every other cell is shrunk by `shrink` (see `SchlegelDiagram::project(...)`).
+    /// This does nothing if the mesh doesn't have any cells.
+    pub fn project_schlegel(&mut self, cell: u32, shrink: f32) {
+        let rebuild = match self.schlegel {
+            Some(ref diagram) => diagram.get_cell() != cell,
+            None => true,
+        };
+        if rebuild {
+            self.schlegel = self.mesh.get_schlegel_diagram(cell);
+        }
+
+        if let Some(ref diagram) = self.schlegel {
+            let vertices = diagram.project(shrink).concat();
+
+            unsafe {
+                gl::NamedBufferSubData(
+                    self.buffer_cell_vertices,
+                    0,
+                    (vertices.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                    vertices.as_ptr() as *const GLvoid,
+                );
+            }
+        }
+    }
+
+    /// Stereographically projects the edges of this renderer's mesh (taking into account
+    /// the mesh's current transform) with `projection`. See
+    /// `StereographicProjection::project_edges(...)`.
+    pub fn project_stereographic(&mut self, projection: &StereographicProjection) {
+        let vertices = projection.project_edges(&self.mesh);
+        self.curve_vertex_count = vertices.len();
+
+        unsafe {
+            // Segments near the pole are skipped, so the number of vertices can change
+            // along with the pole: the buffer is re-allocated each time.
+            gl::NamedBufferData(
+                self.buffer_curve_vertices,
+                (vertices.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STREAM_DRAW,
+            );
+        }
+    }
+
+    /// Draws the curved edges that were computed by the last call to
+    /// `project_stereographic(...)`. Like the edges of the net or Schlegel diagram, these
+    /// should be drawn with an orthographic 4D -> 3D projection.
+    pub fn draw_curves(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao_curves);
+            gl::DrawArrays(gl::LINES, 0, self.curve_vertex_count as i32);
+        }
+    }
+
+    /// Draws the edges of each cell of the mesh, as computed by the last call to `unfold(...)`
+    /// or `project_schlegel(...)`. Note that these vertices are already expressed in 3D
+    /// coordinates (plus a depth cue), so they should be drawn with an orthographic 4D -> 3D
+    /// projection.
+    pub fn draw_cells(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao_cells);
+            gl::DrawElements(
+                gl::LINES,
+                self.cell_index_count as i32,
+                gl::UNSIGNED_INT,
+                ptr::null(),
+            );
+        }
+    }
+
+    /// Draws a 3-dimensional slice of the 4-dimensional mesh.
+    pub fn draw_slice(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao_slice);
+
+            // Bind the buffer that contains indirect draw commands.
+            gl::BindBuffer(gl::DRAW_INDIRECT_BUFFER, self.buffer_indirect_commands);
+
+            // Dispatch indirect draw commands.
+            gl::MultiDrawArraysIndirect(
+                gl::TRIANGLES,
+                ptr::null() as *const GLvoid,
+                self.mesh.get_tetrahedra().len() as i32,
+                mem::size_of::<DrawCommand>() as i32,
+            );
+        }
+    }
+
+    /// Draws a 3-dimensional projection of the 4-dimensional tetrahedra that make up this
+    /// mesh.
+    pub fn draw_tetrahedra(&self) {
+        unsafe {
+            let number_of_tetrahedral_edges =
+                self.mesh.get_tetrahedra().len() * Tetrahedron::get_number_of_edges() * 2;
+
+            gl::BindVertexArray(self.vao_tetrahedra);
+            gl::DrawElements(
+                gl::LINES,
+                number_of_tetrahedral_edges as i32,
+                gl::UNSIGNED_INT,
+                ptr::null(),
+            );
+        }
+    }
+
+    /// Draws a 3-dimensional projection of the skeleton (wireframe) of this polychoron.
+    pub fn draw_edges(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao_edges);
+            gl::DrawElements(
+                gl::LINES,
+                self.mesh.get_edges().len() as i32,
+                gl::UNSIGNED_INT,
+                ptr::null(),
+            );
+        }
+    }
+
+    /// Gathers all of the necessary vertex attributes required to render the tetrahedra
+    /// that make up this mesh. In particular, this function returns the vertex positions
+    /// and colors.
+    fn gather_tetrahedra_attributes(&self) -> (Vec<Vector4<f32>>, Vec<Vector4<f32>>) {
+        // Set up CPU-side buffers.
+        let mut vertices = Vec::new();
+        let mut colors = Vec::new();
+
+        for tetra in self.mesh.get_tetrahedra().iter() {
+            // First, push back all of this tetrahedron's vertices.
+            vertices.extend_from_slice(tetra.get_vertices());
+
+            // Any tetrahedral slice can have at most 6 vertices (a quadrilateral, 2 triangles).
+            let max_vertices_per_slice = 6;
+
+            // Next, push back all of this tetrahedron's colors (currently, we are
+            // using the cell centroid to generate some sort of shading / colors).
+            // TODO: see notes on attribute divisors in `init_slice_objects(...)`
+            for _ in 0..max_vertices_per_slice {
+                colors.push(tetra.cell_centroid);
+            }
+        }
+
+        (vertices, colors)
+    }
+
+    /// Gather all of the edge indices for the tetrahedra that make up this mesh.
+    fn gather_tetrahedra_indices(&self) -> Vec<u32> {
+        let mut indices = Vec::new();
+
+        // Gather the base indices used for drawing a tetrahedron, i.e.
+        // `[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]`, and apply
+        // relative offsets.
+        let local_indices = Tetrahedron::get_edge_indices();
+
+        for i in 0..self.mesh.get_tetrahedra().len() {
+            // Generate a new set of edge indices for this tetrahedron.
+            for (a, b) in local_indices.iter() {
+                // Create a new set of indices to draw the current tetrahedron. First,
+                // we add `4 * i`, since each tetrahedron has 4 vertices.
+                let offset = (Tetrahedron::get_number_of_vertices() * i) as u32;
+
+                indices.push(a + offset);
+                indices.push(b + offset);
+            }
+        }
+
+        indices
+    }
+
+    /// Initializes all OpenGL objects (VAOs, buffers, etc.): see functions below.
+    fn init_render_objects(&mut self) {
+        self.init_slice_objects();
+        self.init_tetrahedra_objects();
+        self.init_edges_objects();
+        self.init_cell_slices_objects();
+        self.init_cells_objects();
+        self.init_curves_objects();
+    }
+
+    /// Initializes all OpenGL objects for rendering a 3-dimensional slice of this
+    /// 4-dimensional polychoron.
+    fn init_slice_objects(&mut self) {
+        unsafe {
+            gl::CreateVertexArrays(1, &mut self.vao_slice);
+
+            // Set up attribute #0: positions.
+            const ATTR_POS: u32 = 0;
+            const BINDING_POS: u32 = 0;
+            gl::EnableVertexArrayAttrib(self.vao_slice, ATTR_POS);
+            gl::VertexArrayAttribFormat(
+                self.vao_slice,
+                ATTR_POS,
+                self.mesh.get_definition().components_per_vertex as i32,
+                gl::FLOAT,
+                gl::FALSE,
+                0,
+            );
+            gl::VertexArrayAttribBinding(self.vao_slice, ATTR_POS, BINDING_POS);
+
+            // Set up attribute #1: colors.
+            const ATTR_COL: u32 = 1;
+            const BINDING_COL: u32 = 1;
+            gl::EnableVertexArrayAttrib(self.vao_slice, ATTR_COL);
+            gl::VertexArrayAttribFormat(
+                self.vao_slice,
+                ATTR_COL,
+                self.mesh.get_definition().components_per_vertex as i32,
+                gl::FLOAT,
+                gl::FALSE,
+                0,
+            );
+            gl::VertexArrayAttribBinding(self.vao_slice, ATTR_COL, BINDING_COL);
+            // TODO: gl::VertexArrayBindingDivisor(self.vao_slice, BINDING_COL, 6);
+
+            let (vertices, colors) = self.gather_tetrahedra_attributes();
+
+            // Any tetrahedral slice can have at most 6 vertices (a quadrilateral, 2 triangles).
+            let max_vertices_per_slice = 6;
+
+            let vertices_size = mem::size_of::<Vector4<f32>>()
+                * Tetrahedron::get_number_of_vertices()
+                * self.mesh.get_tetrahedra().len();
+            let colors_size = mem::size_of::<Vector4<f32>>()
+                * max_vertices_per_slice
+                * self.mesh.get_tetrahedra().len();
+
+            // The VBO that will be associated with the vertex attribute #1, which does not change
+            // throughout the lifetime of the program (thus, we use the flag `STATIC_DRAW` below).
+            gl::CreateBuffers(1, &mut self.buffer_slice_colors);
+            gl::NamedBufferData(
+                self.buffer_slice_colors,
+                colors_size as isize,
+                colors.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            // The buffer that will be bound at index #0 and read from.
+            gl::CreateBuffers(1, &mut self.buffer_tetrahedra);
+            gl::NamedBufferData(
+                self.buffer_tetrahedra,
+                vertices_size as isize,
+                vertices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            // Items that will be written to on the GPU (more or less every frame).
+            // ...
+
+            // The buffer of slice vertices that will be written to whenever the slicing hyperplane moves.
+            let mut alloc_size = mem::size_of::<Vector4<f32>>()
+                * max_vertices_per_slice
+                * self.mesh.get_tetrahedra().len();
+            gl::CreateBuffers(1, &mut self.buffer_slice_vertices);
+            gl::NamedBufferData(
+                self.buffer_slice_vertices,
+                alloc_size as isize,
+                ptr::null() as *const GLvoid,
+                gl::STREAM_DRAW,
+            );
+
+            // The buffer of draw commands that will be filled out by the compute shader dispatch.
+            alloc_size = mem::size_of::<DrawCommand>() * self.mesh.get_tetrahedra().len();
+            gl::CreateBuffers(1, &mut self.buffer_indirect_commands);
+            gl::NamedBufferData(
+                self.buffer_indirect_commands,
+                alloc_size as isize,
+                ptr::null() as *const GLvoid,
+                gl::STREAM_DRAW,
+            );
+
+            // Set up SSBO bind points.
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 0, self.buffer_tetrahedra);
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 1, self.buffer_slice_vertices);
+            gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 2, self.buffer_indirect_commands);
+
+            // Setup vertex attribute bindings.
+            gl::VertexArrayVertexBuffer(
+                self.vao_slice,
+                BINDING_POS,
+                self.buffer_slice_vertices,
+                0,
+                mem::size_of::<Vector4<f32>>() as i32,
+            );
+            gl::VertexArrayVertexBuffer(
+                self.vao_slice,
+                BINDING_COL,
+                self.buffer_slice_colors,
+                0,
+                mem::size_of::<Vector4<f32>>() as i32,
+            );
+
+            let mut local_size: [i32; 3] = [0; 3];
+            gl::GetProgramiv(
+                self.compute.get_id(),
+                gl::COMPUTE_WORK_GROUP_SIZE,
+                local_size.as_mut_ptr(),
+            );
+        }
+    }
+
+    /// Initializes all OpenGL objects for rendering wireframes of all of the
+    /// tetrahedra that make up this polychoron, which are embedded in 4-dimensions.
+    fn init_tetrahedra_objects(&mut self) {
+        unsafe {
+            // First, create the vertex array object.
+            gl::CreateVertexArrays(1, &mut self.vao_tetrahedra);
+
+            // Create the element buffer that will hold all of the edge indices for rendering
+            // wireframes of all of the tetrahedra that make up this polychoron.
+            let indices = self.gather_tetrahedra_indices();
+            let indices_size = (indices.len() * mem::size_of::<u32>()) as GLsizeiptr;
+
+            gl::CreateBuffers(1, &mut self.ebo_tetrahedra);
+            gl::NamedBufferData(
+                self.ebo_tetrahedra,
+                indices_size,
+                indices.as_ptr() as *const GLvoid,
+                gl::DYNAMIC_DRAW,
+            );
+
+            gl::EnableVertexArrayAttrib(self.vao_tetrahedra, 0);
+            gl::VertexArrayAttribFormat(
+                self.vao_tetrahedra,
+                0,
+                self.mesh.get_definition().components_per_vertex as i32,
+                gl::FLOAT,
+                gl::FALSE,
+                0,
+            );
+            gl::VertexArrayAttribBinding(self.vao_tetrahedra, 0, 0);
+
+            // Setup vertex attribute bindings: notice that we use the same VBO from above that
+            // holds all of the vertices of the tetrahedra that make up this polychoron.
+            gl::VertexArrayVertexBuffer(
+                self.vao_tetrahedra,
+                0,
+                self.buffer_tetrahedra,
+                0,
+                (mem::size_of::<f32>() * self.mesh.get_definition().components_per_vertex as usize)
+                    as i32,
+            );
+
+            // Bind the EBO to the VAO.
+            gl::VertexArrayElementBuffer(self.vao_tetrahedra, self.ebo_tetrahedra);
+        }
+    }
+
+    /// Initializes all OpenGL objects for rendering the edges of this polychoron.
+    fn init_edges_objects(&mut self) {
+        unsafe {
+            // First, create the vertex array object.
+            gl::CreateVertexArrays(1, &mut self.vao_edges);
+
+            // Set up attribute #0: positions (for now, we ignore colors).
+            const ATTR_POS: u32 = 0;
+            const BINDING_POS: u32 = 0;
+            gl::EnableVertexArrayAttrib(self.vao_edges, ATTR_POS);
+            gl::VertexArrayAttribFormat(
+                self.vao_edges,
+                ATTR_POS,
+                self.mesh.get_definition().components_per_vertex as i32,
+                gl::FLOAT,
+                gl::FALSE,
+                0,
+            );
+            gl::VertexArrayAttribBinding(self.vao_edges, ATTR_POS, BINDING_POS);
+
+            // Create the vertex buffer that will hold all of the polychoron's unique vertices.
+            let vertices_size =
+                (self.mesh.get_vertices().len() * mem::size_of::<Vector4<f32>>()) as GLsizeiptr;
+
+            gl::CreateBuffers(1, &mut self.vbo_edges);
+            gl::NamedBufferData(
+                self.vbo_edges,
+                vertices_size as isize,
+                self.mesh.get_vertices().as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            // Setup vertex attribute bindings.
+            gl::VertexArrayVertexBuffer(
+                self.vao_edges,
+                BINDING_POS,
+                self.vbo_edges,
+                0,
+                mem::size_of::<Vector4<f32>>() as i32,
+            );
+
+            // Create the element buffer that will hold all of the edge indices for rendering
+            // a wireframe of this polychoron.
+            let edges_size = (self.mesh.get_edges().len() * mem::size_of::<u32>()) as GLsizeiptr;
+
+            gl::CreateBuffers(1, &mut self.ebo_edges);
+            gl::NamedBufferData(
+                self.ebo_edges,
+                edges_size,
+                self.mesh.get_edges().as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            // Bind the EBO to the VAO.
+            gl::VertexArrayElementBuffer(self.vao_edges, self.ebo_edges);
+        }
+    }
+
+    /// Initializes all OpenGL objects for rendering the polygonal cross-sections of the
+    /// cells of this polychoron. The buffers themselves are filled by `slice_cells(...)`.
+    fn init_cell_slices_objects(&mut self) {
+        unsafe {
+            gl::CreateVertexArrays(1, &mut self.vao_cell_slices);
+            gl::CreateBuffers(1, &mut self.buffer_cell_slice_vertices);
+            gl::CreateBuffers(1, &mut self.buffer_cell_slice_colors);
+
+            // Set up attribute #0 (positions) and #1 (colors).
+            for (attribute, buffer) in [
+                (0, self.buffer_cell_slice_vertices),
+                (1, self.buffer_cell_slice_colors),
+            ]
+            .iter()
+            {
+                gl::EnableVertexArrayAttrib(self.vao_cell_slices, *attribute);
+                gl::VertexArrayAttribFormat(
+                    self.vao_cell_slices,
+                    *attribute,
+                    self.mesh.get_definition().components_per_vertex as i32,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    0,
+                );
+                gl::VertexArrayAttribBinding(self.vao_cell_slices, *attribute, *attribute);
+                gl::VertexArrayVertexBuffer(
+                    self.vao_cell_slices,
+                    *attribute,
+                    *buffer,
+                    0,
+                    mem::size_of::<Vector4<f32>>() as i32,
+                );
+            }
+        }
+    }
+
+    /// Initializes all OpenGL objects for rendering the cells of this polychoron separately.
+    /// The vertices are filled in by `unfold(...)` or `project_schlegel(...)`, but the colors
+    /// and edge indices never change: both the net and the Schlegel diagram list the
+    /// vertices of each cell in the same order.
+    fn init_cells_objects(&mut self) {
+        let mut vertices = Vec::new();
+        let mut colors = Vec::new();
+        let mut indices = Vec::new();
+
+        if let Some(ref net) = self.net {
+            let cells = net.unfold(0.0);
+            vertices = cells.concat();
+
+            let mut offset = 0;
+            for (i, cell) in cells.iter().enumerate() {
+                for [a, b] in net.get_cell_edges(i as u32).iter() {
+                    indices.push(a + offset);
+                    indices.push(b + offset);
+                }
+                for _ in 0..cell.len() {
+                    colors.push(net.get_cell_centroid(i as u32));
+                }
+                offset += cell.len() as u32;
+            }
+        }
+        self.cell_index_count = indices.len();
+
+        unsafe {
+            gl::CreateVertexArrays(1, &mut self.vao_cells);
+            gl::CreateBuffers(1, &mut self.buffer_cell_vertices);
+            gl::CreateBuffers(1, &mut self.buffer_cell_colors);
+            gl::CreateBuffers(1, &mut self.ebo_cells);
+
+            gl::NamedBufferData(
+                self.buffer_cell_vertices,
+                (vertices.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                vertices.as_ptr() as *const GLvoid,
+                gl::DYNAMIC_DRAW,
+            );
+            gl::NamedBufferData(
+                self.buffer_cell_colors,
+                (colors.len() * mem::size_of::<Vector4<f32>>()) as isize,
+                colors.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+            gl::NamedBufferData(
+                self.ebo_cells,
+                (indices.len() * mem::size_of::<u32>()) as isize,
+                indices.as_ptr() as *const GLvoid,
+                gl::STATIC_DRAW,
+            );
+
+            // Set up attribute #0 (positions) and #1 (colors).
+            for (attribute, buffer) in
+                [(0, self.buffer_cell_vertices), (1, self.buffer_cell_colors)].iter()
+            {
+                gl::EnableVertexArrayAttrib(self.vao_cells, *attribute);
+                gl::VertexArrayAttribFormat(
+                    self.vao_cells,
+                    *attribute,
+                    self.mesh.get_definition().components_per_vertex as i32,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    0,
+                );
+                gl::VertexArrayAttribBinding(self.vao_cells, *attribute, *attribute);
+                gl::VertexArrayVertexBuffer(
+                    self.vao_cells,
+                    *attribute,
+                    *buffer,
+                    0,
+                    mem::size_of::<Vector4<f32>>() as i32,
+                );
+            }
+
+            gl::VertexArrayElementBuffer(self.vao_cells, self.ebo_cells);
+        }
+    }
+
+    /// Initializes all OpenGL objects for rendering the stereographic projection of this
+    /// polychoron. The buffer itself is filled by `project_stereographic(...)`.
+    fn init_curves_objects(&mut self) {
+        unsafe {
+            gl::CreateVertexArrays(1, &mut self.vao_curves);
+            gl::CreateBuffers(1, &mut self.buffer_curve_vertices);
+
+            // Set up attribute #0 (positions) and #1 (colors): the curves are colored by
+            // their (projected) positions, so both attributes read from the same buffer.
+            for attribute in [0, 1].iter() {
+                gl::EnableVertexArrayAttrib(self.vao_curves, *attribute);
+                gl::VertexArrayAttribFormat(
+                    self.vao_curves,
+                    *attribute,
+                    self.mesh.get_definition().components_per_vertex as i32,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    0,
+                );
+                gl::VertexArrayAttribBinding(self.vao_curves, *attribute, 0);
+            }
+            gl::VertexArrayVertexBuffer(
+                self.vao_curves,
+                0,
+                self.buffer_curve_vertices,
+                0,
+                mem::size_of::<Vector4<f32>>() as i32,
+            );
+        }
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        unsafe {
+            let vertex_arrays = [
+                self.vao_slice,
+                self.vao_tetrahedra,
+                self.vao_edges,
+                self.vao_cell_slices,
+                self.vao_cells,
+                self.vao_curves,
+            ];
+            gl::DeleteVertexArrays(vertex_arrays.len() as i32, vertex_arrays.as_ptr());
+
+            let buffers = [
+                self.buffer_tetrahedra,
+                self.buffer_slice_colors,
+                self.buffer_slice_vertices,
+                self.buffer_indirect_commands,
+                self.ebo_tetrahedra,
+                self.vbo_edges,
+                self.ebo_edges,
+                self.buffer_cell_slice_vertices,
+                self.buffer_cell_slice_colors,
+                self.buffer_cell_vertices,
+                self.buffer_cell_colors,
+                self.ebo_cells,
+                self.buffer_curve_vertices,
+            ];
+            gl::DeleteBuffers(buffers.len() as i32, buffers.as_ptr());
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::f32;
+
+use cgmath::{InnerSpace, Vector4};
+
+use complex::BoundaryComplex;
+use math;
+use mesh::Mesh;
+
+/// How far outside of the projection cell the eye is placed, as a fraction of the
+/// largest distance from which no other cell would be visible.
+const EYE_DISTANCE: f32 = 0.5;
+
+/// A Schlegel diagram of a (convex) polychoron: a perspective projection of its cells
+/// into the hyperplane of one of them (the "projection cell"), from an eye that is just
+/// outside of that cell. The projection cell becomes the outer hull of the diagram, and
+/// all of the other cells are nested inside of it without overlapping.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Schlegel_diagram`
+pub struct SchlegelDiagram {
+    /// The index of the projection cell
+    cell: u32,
+
+    /// The vertices of the polychoron
+    vertices: Vec<Vector4<f32>>,
+
+    /// The (sorted) vertex indices and centroid of each cell
+    cells: Vec<(Vec<u32>, Vector4<f32>)>,
+
+    /// The position of the eye in 4-space
+    eye: Vector4<f32>,
+
+    /// The centroid of the projection cell, which becomes the origin of the diagram
+    origin: Vector4<f32>,
+
+    /// An orthonormal basis for the hyperplane of the projection cell, followed by its
+    /// (outward-facing) normal
+    basis: [Vector4<f32>; 4],
+
+    /// The scale that is applied to the diagram, so that its outer hull is as large as
+    /// the polychoron itself
+    scale: f32,
+}
+
+impl SchlegelDiagram {
+    /// Builds the Schlegel diagram of `mesh` that projects through the `cell`th cell.
+    /// Meshes that were built directly from tetrahedra don't have any cells, in which case
+    /// this returns `None` (as it does if `cell` is out of range).
+    pub fn new(mesh: &Mesh, cell: u32) -> Option<SchlegelDiagram> {
+        let complex = mesh.get_boundary_complex()?;
+        if cell as usize >= complex.get_number_of_cells() {
+            return None;
+        }
+
+        let vertices = (0..complex.get_number_of_vertices())
+            .map(|vertex| complex.get_vertex(vertex as u32))
+            .collect::<Vec<_>>();
+        let cells = (0..complex.get_number_of_cells() as u32)
+            .map(|i| {
+                (
+                    complex.get_vertices_of_cell(i),
+                    complex.get_cell_centroid(i),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let origin = cells[cell as usize].1;
+        let normal = complex.get_cell_normal(cell);
+        let [b0, b1, b2] = math::get_hyperplane_basis(&normal);
+        let eye = origin + normal * get_eye_distance(&complex, cell);
+
+        let mut diagram = SchlegelDiagram {
+            cell,
+            vertices,
+            cells,
+            eye,
+            origin,
+            basis: [b0, b1, b2, normal],
+            scale: 1.0,
+        };
+
+        // The vertices of the projection cell are left in place by the projection.
+        let cell_radius = diagram.cells[cell as usize]
+            .0
+            .iter()
+            .map(|vertex| (diagram.vertices[*vertex as usize] - origin).magnitude())
+            .fold(0.0, f32::max);
+        if cell_radius > 0.0 {
+            diagram.scale = mesh.get_circumradius() / cell_radius;
+        }
+
+        Some(diagram)
+    }
+
+    /// Returns the index of the projection cell.
+    pub fn get_cell(&self) -> u32 {
+        self.cell
+    }
+
+    /// Returns the position of the eye (in 4-space) that the diagram is projected from.
+    pub fn get_eye(&self) -> Vector4<f32> {
+        self.eye
+    }
+
+    /// Projects each cell of the polychoron and returns its vertices, in the same order as
+    /// `BoundaryComplex::get_vertices_of_cell(...)`. The `x`, `y`, and `z`-coordinates lie
+    /// inside of the hyperplane of the projection cell (centered on its centroid), and the
+    /// `w`-coordinate is the 4-dimensional depth of the original vertex below that
+    /// hyperplane, which can be used as a depth cue.
+    ///
+    /// Every cell other than the projection cell is shrunk towards its own centroid by
+    /// `shrink` before it is projected, where `1` leaves the cells as they are. Shrinking
+    /// the cells leaves gaps between them, which makes the structure of the diagram
+    /// easier to see.
+    pub fn project(&self, shrink: f32) -> Vec<Vec<Vector4<f32>>> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, (vertices, centroid))| {
+                let factor = if i as u32 == self.cell { 1.0 } else { shrink };
+
+                vertices
+                    .iter()
+                    .map(|vertex| {
+                        let point = self.vertices[*vertex as usize];
+                        self.project_point(&(centroid + (point - centroid) * factor))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Projects `point` from the eye into the hyperplane of the projection cell.
+    fn project_point(&self, point: &Vector4<f32>) -> Vector4<f32> {
+        let normal = self.basis[3];
+
+        // Find where the ray from the eye through `point` crosses the hyperplane.
+        let height = normal.dot(self.eye - self.origin);
+        let t = height / normal.dot(self.eye - point);
+        let projected = self.eye + (point - self.eye) * t - self.origin;
+
+        Vector4::new(
+            self.basis[0].dot(projected) * self.scale,
+            self.basis[1].dot(projected) * self.scale,
+            self.basis[2].dot(projected) * self.scale,
+            normal.dot(self.origin - point),
+        )
+    }
+}
+
+/// Returns how far outside of the `cell`th cell the eye should be placed. The eye needs
+/// to be "above" the cell's hyperplane but "below" the hyperplanes of all of the other
+/// cells: otherwise, some of them would be seen from the outside and overlap in the
+/// diagram.
+fn get_eye_distance(complex: &BoundaryComplex, cell: u32) -> f32 {
+    let origin = complex.get_cell_centroid(cell);
+    let normal = complex.get_cell_normal(cell);
+
+    let limit = (0..complex.get_number_of_cells() as u32)
+        .filter(|other| *other != cell)
+        .filter_map(|other| {
+            // The eye crosses the other cell's hyperplane when
+            // `n·(origin + normal·d - centroid) = 0`.
+            let other_normal = complex.get_cell_normal(other);
+            let approach = other_normal.dot(normal);
+            if approach > f32::EPSILON {
+                Some(other_normal.dot(complex.get_cell_centroid(other) - origin) / approach)
+            } else {
+                None
+            }
+        })
+        .fold(f32::INFINITY, f32::min);
+
+    // If no other cell faces the same way (e.g. for one of the caps of a prism),
+    // the eye can be arbitrarily far away: fall back to the size of the polytope.
+    if limit.is_finite() {
+        limit * EYE_DISTANCE
+    } else {
+        (0..complex.get_number_of_vertices() as u32)
+            .map(|vertex| (complex.get_vertex(vertex) - origin).magnitude())
+            .fold(0.0, f32::max)
+    }
+}
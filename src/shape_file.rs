@@ -0,0 +1,332 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use cgmath::Vector4;
+
+use hull;
+use hyperplane::Hyperplane;
+use polychora::{Definition, Polytope};
+
+/// An error that occurred while parsing a shape file, along with the (1-based)
+/// line and column where it was encountered.
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error that occurred while loading a shape file: either the file couldn't be read,
+/// or its contents couldn't be parsed.
+#[derive(Debug)]
+pub enum ShapeFileError {
+    /// The file couldn't be opened or read
+    Io(io::Error),
+
+    /// The contents of the file are malformed
+    Parse(ParseError),
+}
+
+impl fmt::Display for ShapeFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShapeFileError::Io(error) => write!(f, "{}", error),
+            ShapeFileError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ShapeFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ShapeFileError::Io(error) => Some(error),
+            ShapeFileError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ShapeFileError {
+    fn from(error: io::Error) -> ShapeFileError {
+        ShapeFileError::Io(error)
+    }
+}
+
+impl From<ParseError> for ShapeFileError {
+    fn from(error: ParseError) -> ShapeFileError {
+        ShapeFileError::Parse(error)
+    }
+}
+
+/// A single whitespace-separated token, along with its position in the file.
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+/// A simple cursor over the non-empty lines of a shape file.
+struct Lines<'a> {
+    lines: Vec<Vec<Token<'a>>>,
+    current: usize,
+    last_line: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn new(contents: &'a str) -> Lines<'a> {
+        let mut lines = Vec::new();
+        let mut last_line = 0;
+
+        for (line_index, line) in contents.lines().enumerate() {
+            last_line = line_index + 1;
+
+            let mut tokens = Vec::new();
+            let mut start = None;
+
+            // Split the line on whitespace, keeping track of where each token starts.
+            for (column, c) in line.char_indices().chain(Some((line.len(), ' '))) {
+                match (c.is_whitespace(), start) {
+                    (true, Some(s)) => {
+                        tokens.push(Token {
+                            text: &line[s..column],
+                            line: line_index + 1,
+                            column: line[..s].chars().count() + 1,
+                        });
+                        start = None;
+                    }
+                    (false, None) => start = Some(column),
+                    _ => (),
+                }
+            }
+
+            if !tokens.is_empty() {
+                lines.push(tokens);
+            }
+        }
+
+        Lines {
+            lines,
+            current: 0,
+            last_line,
+        }
+    }
+
+    /// Returns the next non-empty line or an error if the end of the file was reached.
+    fn next(&mut self, expected: &str) -> Result<&[Token<'a>], ParseError> {
+        if self.current >= self.lines.len() {
+            return Err(ParseError {
+                line: self.last_line + 1,
+                column: 1,
+                message: format!("unexpected end of file, expected {}", expected),
+            });
+        }
+        self.current += 1;
+
+        Ok(&self.lines[self.current - 1])
+    }
+
+    /// Returns an error if there are any non-empty lines left in the file.
+    fn finish(&self) -> Result<(), ParseError> {
+        match self.lines.get(self.current) {
+            Some(tokens) => Err(error_at(&tokens[0], "unexpected trailing content")),
+            None => Ok(()),
+        }
+    }
+}
+
+fn error_at(token: &Token, message: &str) -> ParseError {
+    ParseError {
+        line: token.line,
+        column: token.column,
+        message: message.to_string(),
+    }
+}
+
+/// Parses a single token as a value of type `T`.
+fn parse_token<T: FromStr>(token: &Token, expected: &str) -> Result<T, ParseError> {
+    token.text.parse::<T>().map_err(|_| {
+        error_at(
+            token,
+            &format!("expected {}, found `{}`", expected, token.text),
+        )
+    })
+}
+
+/// Parses a line that should contain exactly one token: the number of entries in
+/// the section that follows.
+fn parse_count(lines: &mut Lines, section: &str) -> Result<usize, ParseError> {
+    let expected = format!("the number of {}", section);
+    let tokens = lines.next(&expected)?;
+    if tokens.len() != 1 {
+        return Err(error_at(
+            &tokens[1],
+            &format!("expected a single integer (the number of {})", section),
+        ));
+    }
+
+    parse_token(&tokens[0], &expected)
+}
+
+/// Parses a line of vertex indices, checking that each one refers to an existing vertex.
+fn parse_indices(tokens: &[Token], number_of_vertices: usize) -> Result<Vec<u32>, ParseError> {
+    let mut indices = Vec::new();
+
+    for token in tokens.iter() {
+        let index = parse_token::<u32>(token, "a vertex index")?;
+        if index as usize >= number_of_vertices {
+            return Err(error_at(
+                token,
+                &format!(
+                    "vertex index {} is out of range (there are {} vertices)",
+                    index, number_of_vertices
+                ),
+            ));
+        }
+        indices.push(index);
+    }
+
+    Ok(indices)
+}
+
+/// A polytope that has been loaded from a shape file at runtime, rather than one
+/// of the built-in `Polychoron`s.
+///
+/// Shape files use the same format as Paul Bourke's (see the documentation of
+/// `Polychoron`): a vertex count followed by one `x y z w` line per vertex, an edge
+/// count followed by one `v0 v1` line per edge, and a face count followed by one line
+/// of vertex indices per face. Blank lines are ignored. Unlike the built-in shapes,
+/// the faces of a loaded shape do not all need to have the same number of vertices.
+pub struct ShapeFile {
+    vertices: Vec<Vector4<f32>>,
+    edges: Vec<u32>,
+    faces: Vec<Vec<u32>>,
+    h_representation: Vec<Hyperplane>,
+    definition: Definition,
+}
+
+impl ShapeFile {
+    /// Loads and parses the shape file at `path`.
+    pub fn load(path: &Path) -> Result<ShapeFile, ShapeFileError> {
+        let contents = fs::read_to_string(path)?;
+
+        Ok(ShapeFile::parse(&contents)?)
+    }
+
+    /// Parses the contents of a shape file.
+    pub fn parse(contents: &str) -> Result<ShapeFile, ParseError> {
+        let mut lines = Lines::new(contents);
+
+        // The counts come straight from the file, so they aren't used to preallocate any
+        // of the lists: a malformed count would otherwise abort with a capacity overflow
+        // (or exhaust memory) before the missing lines are reported.
+        let number_of_vertices = parse_count(&mut lines, "vertices")?;
+        let mut vertices = Vec::new();
+        for _ in 0..number_of_vertices {
+            let tokens = lines.next("a vertex")?;
+            if tokens.len() != 4 {
+                return Err(error_at(
+                    &tokens[0],
+                    &format!("expected 4 coordinates per vertex, found {}", tokens.len()),
+                ));
+            }
+
+            let mut coordinates = [0.0; 4];
+            for (coordinate, token) in coordinates.iter_mut().zip(tokens.iter()) {
+                *coordinate = parse_token::<f32>(token, "a coordinate")?;
+            }
+            vertices.push(Vector4::from(coordinates));
+        }
+
+        let number_of_edges = parse_count(&mut lines, "edges")?;
+        let mut edges = Vec::new();
+        for _ in 0..number_of_edges {
+            let tokens = lines.next("an edge")?;
+            if tokens.len() != 2 {
+                return Err(error_at(
+                    &tokens[0],
+                    &format!("expected 2 vertex indices per edge, found {}", tokens.len()),
+                ));
+            }
+            edges.extend(parse_indices(tokens, number_of_vertices)?);
+        }
+
+        let number_of_faces = parse_count(&mut lines, "faces")?;
+        let mut faces = Vec::new();
+        for _ in 0..number_of_faces {
+            let tokens = lines.next("a face")?;
+            if tokens.len() < 3 {
+                return Err(error_at(
+                    &tokens[0],
+                    &format!(
+                        "expected at least 3 vertex indices per face, found {}",
+                        tokens.len()
+                    ),
+                ));
+            }
+            faces.push(parse_indices(tokens, number_of_vertices)?);
+        }
+
+        lines.finish()?;
+
+        // Shape files don't contain any information about cells, so we have to recover
+        // them from the vertices.
+        let h_representation = hull::get_bounding_hyperplanes(&vertices);
+
+        let cells = h_representation
+            .iter()
+            .map(|hyperplane| {
+                (0..faces.len() as u32)
+                    .filter(|index| {
+                        faces[*index as usize]
+                            .iter()
+                            .all(|vertex| hyperplane.inside(&vertices[*vertex as usize]))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let definition = Definition::from_topology(&faces, &cells);
+
+        Ok(ShapeFile {
+            vertices,
+            edges,
+            faces,
+            h_representation,
+            definition,
+        })
+    }
+}
+
+impl Polytope for ShapeFile {
+    /// Returns a topological definition corresponding to this shape. Note that any of
+    /// the per-face or per-cell counts will be 0 if they vary across the shape.
+    fn get_definition(&self) -> Definition {
+        self.definition
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        self.vertices.clone()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        self.edges.clone()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
@@ -0,0 +1,136 @@
+use cgmath::{InnerSpace, Vector4};
+
+use camera::{Camera, FourCamera};
+use math;
+use mesh::Mesh;
+use utilities;
+
+/// How close (in terms of `1 - cos(θ)`, where `θ` is the angle from the pole) a point can
+/// get to the pole before it is no longer projected: points near the pole are sent off
+/// towards infinity.
+const POLE_TOLERANCE: f32 = 1.0e-3;
+
+/// A stereographic projection from the 3-sphere to 3-space. Points on the 3-sphere are
+/// projected from the "pole" onto the hyperplane through the center of the sphere that
+/// is orthogonal to the pole. Since this is conformal, the cells of a polychoron whose
+/// vertices lie on a 3-sphere are projected as curved (but undistorted) polyhedra that
+/// tile 3-space, with the cells nearest to the pole being the largest.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Stereographic_projection`
+pub struct StereographicProjection {
+    /// The (unit) direction of the pole, relative to the center of the sphere
+    pole: Vector4<f32>,
+
+    /// An orthonormal basis for the hyperplane that the sphere is projected onto
+    basis: [Vector4<f32>; 3],
+
+    /// The number of segments that each edge is subdivided into
+    segments: usize,
+}
+
+impl StereographicProjection {
+    /// Creates a stereographic projection from the point on the 3-sphere in the direction
+    /// of `pole`, where each edge is subdivided into `segments` segments.
+    pub fn new(pole: &Vector4<f32>, segments: usize) -> StereographicProjection {
+        StereographicProjection {
+            pole: pole.normalize(),
+            basis: math::get_hyperplane_basis(pole),
+            segments: segments.max(1),
+        }
+    }
+
+    /// Creates a stereographic projection whose pole is placed where `camera` is looking
+    /// from, and whose image is oriented like the camera's view: the `x`, `y`, and `z`-axes
+    /// of the image match the axes of the camera's look-at matrix.
+    pub fn from_camera(camera: &FourCamera, segments: usize) -> StereographicProjection {
+        StereographicProjection {
+            pole: (camera.get_from() - camera.get_to()).normalize(),
+            basis: [
+                camera.get_look_at().x,
+                camera.get_look_at().y,
+                camera.get_look_at().z,
+            ],
+            segments: segments.max(1),
+        }
+    }
+
+    /// Returns the (unit) direction of the pole.
+    pub fn get_pole(&self) -> Vector4<f32> {
+        self.pole
+    }
+
+    /// Projects a `point` on the unit 3-sphere (centered at the origin). The `x`, `y`, and
+    /// `z`-coordinates of the result are the projected point, and the `w`-coordinate is
+    /// the cosine of the angle between the point and the pole, which can be used as a
+    /// depth cue. Returns `None` if the point is too close to the pole.
+    pub fn project_point(&self, point: &Vector4<f32>) -> Option<Vector4<f32>> {
+        let height = point.dot(self.pole);
+        if 1.0 - height < POLE_TOLERANCE {
+            return None;
+        }
+
+        Some(Vector4::new(
+            self.basis[0].dot(*point) / (1.0 - height),
+            self.basis[1].dot(*point) / (1.0 - height),
+            self.basis[2].dot(*point) / (1.0 - height),
+            height,
+        ))
+    }
+
+    /// Projects the edges of `mesh` (after applying its transform) and returns them as a
+    /// list of line segments, i.e. pairs of vertices. The vertices of the mesh are first
+    /// pushed onto its circumscribed 3-sphere, and each edge is subdivided along the arc of
+    /// the great circle between its endpoints, so that it is projected as a curve. Segments
+    /// that are too close to the pole are skipped. The result is scaled by the circumradius
+    /// of the mesh, so that it is roughly the same size as the mesh itself.
+    pub fn project_edges(&self, mesh: &Mesh) -> Vec<Vector4<f32>> {
+        let transform = mesh.get_transform();
+        let center = transform * mesh.get_boundary_centroid();
+        let radius = mesh.get_circumradius();
+
+        let mut lines = Vec::new();
+        for i in 0..mesh.get_number_of_edges() {
+            let (a, b) = mesh.get_vertices_for_edge(i as u32);
+            let a = (transform * a - center).normalize();
+            let b = (transform * b - center).normalize();
+
+            let arc = (0..=self.segments)
+                .map(|step| {
+                    let point = slerp(&a, &b, step as f32 / self.segments as f32);
+                    self.project_point(&point).map(|projected| {
+                        Vector4::new(
+                            projected.x * radius,
+                            projected.y * radius,
+                            projected.z * radius,
+                            projected.w,
+                        )
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for pair in arc.windows(2) {
+                if let (Some(start), Some(end)) = (pair[0], pair[1]) {
+                    lines.push(start);
+                    lines.push(end);
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+/// Spherical linear interpolation between the unit vectors `a` and `b` (along the arc of
+/// the great circle between them) by `t`.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Slerp`
+fn slerp(a: &Vector4<f32>, b: &Vector4<f32>, t: f32) -> Vector4<f32> {
+    let angle = utilities::saturate_between(a.dot(*b), -1.0, 1.0).acos();
+
+    // Nearly identical vectors can simply be interpolated linearly.
+    if angle.sin() < 1.0e-6 {
+        return (a + (b - a) * t).normalize();
+    }
+
+    (a * ((1.0 - t) * angle).sin() + b * (t * angle).sin()) / angle.sin()
+}
@@ -1,11 +1,63 @@
-use cgmath::{self, Vector4};
+use std::cmp::Ordering;
+
+use cgmath::{InnerSpace, Matrix4, SquareMatrix, Vector3, Vector4, Zero};
+
+use hyperplane::Hyperplane;
+use math;
 
 pub trait Tetrahedralize {
     fn generate() -> Vec<Tetrahedron>;
 }
 
+/// The result of slicing a single tetrahedron with a hyperplane. The slice of a
+/// tetrahedron is either empty, a triangle, or a quadrilateral.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Slice {
+    /// The tetrahedron does not intersect the hyperplane
+    Empty,
+
+    /// The hyperplane cuts off a single vertex of the tetrahedron
+    Triangle([Vector4<f32>; 3]),
+
+    /// The hyperplane separates two pairs of vertices of the tetrahedron: the vertices
+    /// are sorted so that they form a closed polygon
+    Quad([Vector4<f32>; 4]),
+}
+
+impl Slice {
+    /// Returns the (0, 3, or 4) vertices of this slice.
+    pub fn get_vertices(&self) -> &[Vector4<f32>] {
+        match self {
+            Slice::Empty => &[],
+            Slice::Triangle(vertices) => vertices,
+            Slice::Quad(vertices) => vertices,
+        }
+    }
+
+    /// Returns the triangles that make up this slice, in the same order that they are
+    /// drawn by the slicing compute shader. A quad is split into two triangles via
+    /// `Tetrahedron::get_quad_indices()`.
+    pub fn get_triangles(&self) -> Vec<[Vector4<f32>; 3]> {
+        match self {
+            Slice::Empty => Vec::new(),
+            Slice::Triangle(vertices) => vec![*vertices],
+            Slice::Quad(vertices) => Tetrahedron::get_quad_indices()
+                .iter()
+                .map(|(a, b, c)| {
+                    [
+                        vertices[*a as usize],
+                        vertices[*b as usize],
+                        vertices[*c as usize],
+                    ]
+                })
+                .collect(),
+        }
+    }
+}
+
 /// A struct representing a tetrahedron (3-simplex) embedded in 4-dimensions. This
 /// is the building block for all 4-dimensional meshes in the `four` renderer.
+#[derive(Copy, Clone, Debug)]
 pub struct Tetrahedron {
     /// The 4 vertices that make up this tetrahedron
     vertices: [Vector4<f32>; 4],
@@ -47,6 +99,24 @@ impl Tetrahedron {
         self.cell_centroid
     }
 
+    /// Returns the (3-dimensional) volume of this tetrahedron.
+    pub fn get_volume(&self) -> f32 {
+        self.get_orthogonal().magnitude() / 6.0
+    }
+
+    /// Returns a unit vector that is orthogonal to the 3-dimensional hyperplane that this
+    /// tetrahedron lies in. Its sign is arbitrary.
+    pub fn get_normal(&self) -> Vector4<f32> {
+        self.get_orthogonal().normalize()
+    }
+
+    /// Returns the 4D cross product of the edges leaving the first vertex, whose magnitude
+    /// is the volume of the parallelepiped spanned by those edges.
+    fn get_orthogonal(&self) -> Vector4<f32> {
+        let [a, b, c, d] = self.vertices;
+        math::cross(&(b - a), &(c - a), &(d - a))
+    }
+
     /// Note that OpenGL expects these to be `u32`s.
     pub fn get_edge_indices() -> [(u32, u32); 6] {
         [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
@@ -74,4 +144,100 @@ impl Tetrahedron {
     pub fn get_quad_indices() -> [(u32, u32, u32); 2] {
         [(0, 1, 2), (0, 2, 3)]
     }
+
+    /// Slices this tetrahedron with `hyperplane`. This is a CPU reference implementation
+    /// of the slicing compute shader (`shaders/compute_slice.glsl`): it returns the same
+    /// points of intersection, in the same order.
+    pub fn slice(&self, hyperplane: &Hyperplane) -> Slice {
+        self.slice_with_transform(&Matrix4::identity(), hyperplane)
+    }
+
+    /// Slices this tetrahedron with `hyperplane`, after transforming each of its vertices
+    /// by `transform` (like the `u_transform` uniform of the slicing compute shader).
+    pub fn slice_with_transform(&self, transform: &Matrix4<f32>, hyperplane: &Hyperplane) -> Slice {
+        let side = |point: &Vector4<f32>| hyperplane.signed_distance(point);
+
+        // Find the points where each of the tetrahedron's edges cross the hyperplane.
+        let mut intersections = Vec::new();
+        let mut touching = [false; 4];
+        for (i, j) in Tetrahedron::get_edge_indices().iter() {
+            let a = transform * self.vertices[*i as usize];
+            let b = transform * self.vertices[*j as usize];
+
+            // Note that this will be NaN (and therefore ignored) for edges that lie
+            // inside of the hyperplane.
+            let t = -side(&a) / (side(&b) - side(&a));
+
+            if (0.0..=1.0).contains(&t) {
+                // A vertex that lies in the hyperplane is shared by 3 edges, but it should
+                // only be counted once.
+                let vertex = if t == 0.0 {
+                    Some(*i)
+                } else if t == 1.0 {
+                    Some(*j)
+                } else {
+                    None
+                };
+                if let Some(vertex) = vertex {
+                    if touching[vertex as usize] {
+                        continue;
+                    }
+                    touching[vertex as usize] = true;
+                }
+
+                intersections.push(a + (b - a) * t);
+            }
+        }
+
+        // Realistically, there should ONLY ever be 0, 3, or 4 intersections: anything
+        // else is ignored (as it is in the compute shader). Hyperplanes that only touch a
+        // vertex or an edge of the tetrahedron produce 1 or 2 intersections.
+        match intersections.len() {
+            3 => Slice::Triangle([intersections[0], intersections[1], intersections[2]]),
+            4 => {
+                let mut sorted = [Vector4::zero(); 4];
+                for (i, index) in sort_quad(&intersections).iter().enumerate() {
+                    sorted[i] = intersections[*index];
+                }
+                Slice::Quad(sorted)
+            }
+            _ => Slice::Empty,
+        }
+    }
+}
+
+/// Returns the indices of the 4 (coplanar) `points` of a quadrilateral slice, sorted by
+/// their signed angle around the slice's centroid. Like the compute shader, this only
+/// looks at the `xyz` components of each point.
+fn sort_quad(points: &[Vector4<f32>]) -> [usize; 4] {
+    let xyz = |point: &Vector4<f32>| Vector3::new(point.x, point.y, point.z);
+    let centroid = points
+        .iter()
+        .fold(Vector3::zero(), |acc, point| acc + xyz(point))
+        / points.len() as f32;
+
+    // Compute the slice normal (in 3-dimensions).
+    let (a, b, c) = (xyz(&points[0]), xyz(&points[1]), xyz(&points[2]));
+    let n = (c - b).cross(b - a).normalize();
+
+    let first_edge = (a - centroid).normalize();
+
+    let mut angles = [(0, 0.0); 4];
+    for (i, angle) in angles.iter_mut().enumerate().skip(1) {
+        let edge = (xyz(&points[i]) - centroid).normalize();
+
+        let mut signed_angle = first_edge.dot(edge).clamp(-1.0, 1.0).acos();
+        if n.dot(first_edge.cross(edge)) < 0.0 {
+            signed_angle *= -1.0;
+        }
+
+        *angle = (i, signed_angle);
+    }
+
+    // This is a stable sort, just like the insertion sort in the compute shader. Degenerate
+    // quads (with coincident points) produce NaN angles, which the shader never swaps, so
+    // they are treated as equal to everything else.
+    angles.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+
+    [angles[0].0, angles[1].0, angles[2].0, angles[3].0]
 }
@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt;
+
+/// A problem with the topology of a mesh that was found by `Mesh::validate(...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TopologyError {
+    /// The mesh was built directly from tetrahedra, so it has no faces or cells to check
+    MissingTopology,
+
+    /// The Euler characteristic `V - E + F - C` of the mesh isn't zero, which is the
+    /// case for the boundary of any (convex) polychoron
+    EulerCharacteristic {
+        vertices: usize,
+        edges: usize,
+        faces: usize,
+        cells: usize,
+    },
+
+    /// A face belongs to some number of cells other than two
+    FaceNotShared { face: usize, cells: usize },
+
+    /// The number of faces that were found in the hyperplane of a cell doesn't match
+    /// the mesh's definition
+    WrongNumberOfFaces {
+        cell: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    /// A cell has too few faces to enclose any volume
+    DegenerateCell { cell: usize, faces: usize },
+
+    /// Some of the edges of a cell don't belong to exactly two of its faces, so the cell
+    /// isn't a closed polyhedron
+    CellNotClosed { cell: usize, open_edges: usize },
+}
+
+impl fmt::Display for TopologyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TopologyError::MissingTopology => {
+                write!(f, "the mesh doesn't have any faces or cells to validate")
+            }
+            TopologyError::EulerCharacteristic {
+                vertices,
+                edges,
+                faces,
+                cells,
+            } => write!(
+                f,
+                "the Euler characteristic is {} (V = {}, E = {}, F = {}, C = {}), but should be 0",
+                *vertices as i64 - *edges as i64 + *faces as i64 - *cells as i64,
+                vertices,
+                edges,
+                faces,
+                cells
+            ),
+            TopologyError::FaceNotShared { face, cells } => write!(
+                f,
+                "face {} belongs to {} cell(s), but should belong to exactly 2",
+                face, cells
+            ),
+            TopologyError::WrongNumberOfFaces {
+                cell,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} face(s) were found for cell {}, but {} were expected",
+                found, cell, expected
+            ),
+            TopologyError::DegenerateCell { cell, faces } => {
+                write!(f, "cell {} only has {} face(s)", cell, faces)
+            }
+            TopologyError::CellNotClosed { cell, open_edges } => write!(
+                f,
+                "cell {} isn't closed: {} of its edge(s) don't belong to exactly 2 of its faces",
+                cell, open_edges
+            ),
+        }
+    }
+}
+
+impl Error for TopologyError {}
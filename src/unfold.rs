@@ -0,0 +1,411 @@
+use std::collections::VecDeque;
+
+use cgmath::{InnerSpace, Matrix, Matrix4, SquareMatrix, Vector4, Zero};
+
+use complex::BoundaryComplex;
+use math;
+use mesh::Mesh;
+
+/// A single cell of a `Net`, along with the "hinge" that attaches it to its parent.
+struct NetCell {
+    /// The cell that this cell is attached to in the spanning tree (`None` for the root)
+    parent: Option<u32>,
+
+    /// The face that this cell shares with its parent (`None` for the root)
+    hinge: Option<u32>,
+
+    /// A point on the hinge (its centroid), which all of the hinge's points rotate about
+    pivot: Vector4<f64>,
+
+    /// An orthonormal basis for the plane of rotation, which is orthogonal to the hinge
+    /// (the first vector is the normal that this cell's normal rotates towards)
+    plane: [Vector4<f64>; 2],
+
+    /// The angle that this cell needs to rotate by in order to lie inside of its
+    /// parent's hyperplane
+    angle: f64,
+
+    /// The (global) indices of the vertices of this cell
+    vertices: Vec<u32>,
+
+    /// The edges of this cell, as pairs of indices into `vertices`
+    edges: Vec<[u32; 2]>,
+
+    /// The centroid of this cell (before it is unfolded)
+    centroid: Vector4<f32>,
+}
+
+/// The net of a (convex) polychoron: its cells, "unfolded" into a single 3-dimensional
+/// hyperplane, just like the net of a polyhedron is its faces unfolded into a plane. For
+/// the tesseract, this is the Dalí cross.
+///
+/// The net is determined by a spanning tree of the cell adjacency graph: starting from
+/// the leaves, each cell is rotated about the face that it shares with its parent until
+/// it lies in its parent's hyperplane. Everything ends up in the hyperplane of the root
+/// cell, which is then used as the coordinate system of the net.
+///
+/// The folds are computed in double precision, since each cell's transform is composed
+/// with those of all of its ancestors.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Net_(polyhedron)`
+pub struct Net {
+    /// The vertices of the polychoron
+    vertices: Vec<Vector4<f32>>,
+
+    /// The cells of the net, sorted so that each parent comes before its children
+    cells: Vec<NetCell>,
+
+    /// The index of each cell in `cells`, i.e. `cells[order[i]]` is the `i`th cell
+    order: Vec<usize>,
+
+    /// The centroid of the root cell, which becomes the origin of the net
+    origin: Vector4<f64>,
+
+    /// An orthonormal basis for the hyperplane of the root cell, followed by its
+    /// (outward-facing) normal
+    basis: [Vector4<f64>; 4],
+}
+
+impl Net {
+    /// Unfolds `mesh` along a breadth-first spanning tree of its cell adjacency graph,
+    /// rooted at the first cell. Meshes that were built directly from tetrahedra don't
+    /// have any cells, in which case this returns `None`.
+    pub fn new(mesh: &Mesh) -> Option<Net> {
+        let complex = mesh.get_boundary_complex()?;
+        let tree = get_spanning_tree(&complex, 0);
+
+        Net::from_complex(&complex, &tree)
+    }
+
+    /// Unfolds `mesh` along the spanning tree `tree`, where the `i`th entry is the parent
+    /// of the `i`th cell (or `None` for the root). Returns `None` if the mesh doesn't have
+    /// any cells, or if `tree` isn't a spanning tree of the cell adjacency graph (i.e. it
+    /// has more than one root, a cycle, or a parent that doesn't share a face with its child).
+    pub fn with_tree(mesh: &Mesh, tree: &[Option<u32>]) -> Option<Net> {
+        let complex = mesh.get_boundary_complex()?;
+
+        Net::from_complex(&complex, tree)
+    }
+
+    fn from_complex(complex: &BoundaryComplex, tree: &[Option<u32>]) -> Option<Net> {
+        let number_of_cells = complex.get_number_of_cells();
+        if tree.len() != number_of_cells {
+            return None;
+        }
+
+        let mut roots = (0..number_of_cells).filter(|cell| tree[*cell].is_none());
+        let root = roots.next()?;
+        if roots.next().is_some() {
+            return None;
+        }
+
+        let mut children = vec![Vec::new(); number_of_cells];
+        for (cell, parent) in tree.iter().enumerate() {
+            if let Some(parent) = parent {
+                children.get_mut(*parent as usize)?.push(cell as u32);
+            }
+        }
+
+        let vertices = (0..complex.get_number_of_vertices())
+            .map(|vertex| complex.get_vertex(vertex as u32))
+            .collect::<Vec<_>>();
+
+        // The normal of the root cell's hyperplane, which every cell ends up in.
+        let normal = get_cell_normal(complex, root as u32);
+
+        // Visit the cells in breadth-first order, so that each parent is unfolded before
+        // any of its children: any cells that aren't reached are part of a cycle. Along
+        // the way, keep track of the linear part of each cell's transform once the net is
+        // completely unfolded.
+        let mut cells = Vec::with_capacity(number_of_cells);
+        let mut unfolded: Vec<Matrix4<f64>> = Vec::with_capacity(number_of_cells);
+        let mut order = vec![usize::MAX; number_of_cells];
+        let mut queue = VecDeque::new();
+        queue.push_back(root as u32);
+
+        while let Some(cell) = queue.pop_front() {
+            let parent = tree[cell as usize];
+            let hinge = match parent {
+                Some(parent) => Some(
+                    complex
+                        .get_cell_neighbors(cell)
+                        .iter()
+                        .find(|(neighbor, _)| *neighbor == parent)
+                        .map(|(_, face)| *face)?,
+                ),
+                None => None,
+            };
+
+            let parent_linear = match parent {
+                Some(parent) => unfolded[order[parent as usize]],
+                None => Matrix4::identity(),
+            };
+            let net_cell = build_cell(complex, cell, parent, hinge, &parent_linear, &normal);
+            let rotation = math::get_plane_rotation_matrix(
+                &net_cell.plane[0],
+                &net_cell.plane[1],
+                -net_cell.angle,
+            );
+
+            order[cell as usize] = cells.len();
+            cells.push(net_cell);
+            unfolded.push(parent_linear * rotation);
+            queue.extend(children[cell as usize].iter());
+        }
+        if cells.len() != number_of_cells {
+            return None;
+        }
+
+        let origin = cells[0].centroid.cast::<f64>();
+        let [b0, b1, b2] = math::get_hyperplane_basis(&normal.cast::<f32>());
+
+        Some(Net {
+            vertices,
+            cells,
+            order,
+            origin,
+            basis: [b0.cast::<f64>(), b1.cast::<f64>(), b2.cast::<f64>(), normal],
+        })
+    }
+
+    /// Returns the number of cells in this net.
+    pub fn get_number_of_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the index of the cell that the `i`th cell is attached to, or `None` if it
+    /// is the root of the net.
+    pub fn get_parent(&self, i: u32) -> Option<u32> {
+        self.cells[self.order[i as usize]].parent
+    }
+
+    /// Returns the index of the face that the `i`th cell shares with its parent (the
+    /// "hinge" that it rotates about), or `None` if it is the root of the net.
+    pub fn get_hinge(&self, i: u32) -> Option<u32> {
+        self.cells[self.order[i as usize]].hinge
+    }
+
+    /// Returns the angle (in radians) that the `i`th cell rotates by relative to its
+    /// parent as the net is unfolded. This is `π` minus the dihedral angle between the
+    /// two cells.
+    pub fn get_fold_angle(&self, i: u32) -> f32 {
+        self.cells[self.order[i as usize]].angle as f32
+    }
+
+    /// Returns the centroid of the `i`th cell, before it is unfolded.
+    pub fn get_cell_centroid(&self, i: u32) -> Vector4<f32> {
+        self.cells[self.order[i as usize]].centroid
+    }
+
+    /// Returns the edges of the `i`th cell, as pairs of indices into the list of vertices
+    /// that is returned for the cell by `unfold(...)`.
+    pub fn get_cell_edges(&self, i: u32) -> &[[u32; 2]] {
+        &self.cells[self.order[i as usize]].edges
+    }
+
+    /// Returns the transform of each cell when the net is unfolded by `amount`, where `0`
+    /// is the original polychoron and `1` is the flat net. Each transform is a linear part
+    /// followed by a translation, since rotations about a hinge (which doesn't pass
+    /// through the origin) can't be represented by a 4x4 matrix alone.
+    pub fn get_transforms(&self, amount: f32) -> Vec<(Matrix4<f32>, Vector4<f32>)> {
+        self.compose_transforms(f64::from(amount))
+            .iter()
+            .map(|(linear, translation)| (linear.cast::<f32>(), translation.cast::<f32>()))
+            .collect()
+    }
+
+    /// Unfolds the net by `amount` (see `get_transforms(...)`) and returns the vertices of
+    /// each cell (in the same order as `BoundaryComplex::get_vertices_of_cell(...)`),
+    /// expressed in the coordinate system of the root cell: the `x`, `y`, and
+    /// `z`-coordinates lie inside of the root cell's hyperplane, and the `w`-coordinate is
+    /// the distance from it. When `amount` is `1`, every `w`-coordinate is zero.
+    pub fn unfold(&self, amount: f32) -> Vec<Vec<Vector4<f32>>> {
+        self.compose_transforms(f64::from(amount))
+            .iter()
+            .enumerate()
+            .map(|(i, (linear, translation))| {
+                self.cells[self.order[i]]
+                    .vertices
+                    .iter()
+                    .map(|vertex| {
+                        let point =
+                            linear * self.vertices[*vertex as usize].cast::<f64>() + translation;
+                        self.to_net_coordinates(&point)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the (double precision) transform of each cell when the net is unfolded by
+    /// `amount`, in the original order of the cells.
+    fn compose_transforms(&self, amount: f64) -> Vec<(Matrix4<f64>, Vector4<f64>)> {
+        let mut transforms: Vec<(Matrix4<f64>, Vector4<f64>)> =
+            Vec::with_capacity(self.cells.len());
+
+        for cell in self.cells.iter() {
+            let transform = match cell.parent {
+                Some(parent) => {
+                    // First rotate this cell about its hinge (in the parent's original
+                    // frame), then move it along with the parent.
+                    let rotation = math::get_plane_rotation_matrix(
+                        &cell.plane[0],
+                        &cell.plane[1],
+                        -cell.angle * amount,
+                    );
+                    let translation = cell.pivot - rotation * cell.pivot;
+
+                    let (parent_linear, parent_translation) =
+                        transforms[self.order[parent as usize]];
+                    (
+                        parent_linear * rotation,
+                        parent_linear * translation + parent_translation,
+                    )
+                }
+                None => (Matrix4::identity(), Vector4::zero()),
+            };
+            transforms.push(transform);
+        }
+
+        // Return the transforms in the original order of the cells.
+        self.order.iter().map(|index| transforms[*index]).collect()
+    }
+
+    /// Expresses `point` relative to the root cell's centroid and hyperplane.
+    fn to_net_coordinates(&self, point: &Vector4<f64>) -> Vector4<f32> {
+        let d = point - self.origin;
+        Vector4::new(
+            self.basis[0].dot(d) as f32,
+            self.basis[1].dot(d) as f32,
+            self.basis[2].dot(d) as f32,
+            self.basis[3].dot(d) as f32,
+        )
+    }
+}
+
+/// Returns a breadth-first spanning tree of the cell adjacency graph of `complex`,
+/// starting at the cell `root`: the `i`th entry is the parent of the `i`th cell (or `None`
+/// for the root, as well as any cells that can't be reached from it).
+pub fn get_spanning_tree(complex: &BoundaryComplex, root: u32) -> Vec<Option<u32>> {
+    let mut tree = vec![None; complex.get_number_of_cells()];
+    let mut visited = vec![false; complex.get_number_of_cells()];
+    visited[root as usize] = true;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(root);
+    while let Some(cell) = queue.pop_front() {
+        for (neighbor, _) in complex.get_cell_neighbors(cell) {
+            if !visited[neighbor as usize] {
+                visited[neighbor as usize] = true;
+                tree[neighbor as usize] = Some(cell);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    tree
+}
+
+/// Returns the `i`th vertex of `complex` in double precision.
+fn get_vertex(complex: &BoundaryComplex, i: u32) -> Vector4<f64> {
+    complex.get_vertex(i).cast::<f64>()
+}
+
+/// Returns the (outward-facing) unit normal of the hyperplane that best fits the vertices
+/// of `cell`. The vertices of the larger built-in polychora are only given to 6 decimal
+/// places, so the normal found by `BoundaryComplex::get_cell_normal(...)` (from just a few
+/// of them) can be off by a few microradians, which is noticeable once the net is unfolded.
+fn get_cell_normal(complex: &BoundaryComplex, cell: u32) -> Vector4<f64> {
+    let normal = complex.get_cell_normal(cell).cast::<f64>();
+
+    let vertices = complex
+        .get_vertices_of_cell(cell)
+        .iter()
+        .map(|vertex| get_vertex(complex, *vertex))
+        .collect::<Vec<_>>();
+    let centroid = vertices
+        .iter()
+        .fold(Vector4::zero(), |sum, vertex| sum + vertex)
+        / vertices.len() as f64;
+    let covariance = vertices.iter().fold(Matrix4::zero(), |sum, vertex| {
+        let d = vertex - centroid;
+        sum + Matrix4::from_cols(d * d.x, d * d.y, d * d.z, d * d.w)
+    });
+
+    // The best fit is the eigenvector of the covariance matrix with the smallest eigenvalue:
+    // starting from a normal that is already close, one step of inverse iteration finds it.
+    // If the vertices are exactly coplanar, the matrix can't be inverted, but then the
+    // normal is already exact.
+    match covariance.invert() {
+        Some(inverse) => {
+            let fitted = (inverse * normal).normalize();
+            if fitted.dot(normal) < 0.0 {
+                -fitted
+            } else {
+                fitted
+            }
+        }
+        None => normal,
+    }
+}
+
+/// Builds the `cell`th cell of a net, which is attached to `parent` via the face `hinge`.
+/// `parent_linear` is the linear part of the parent's transform once the net is unfolded,
+/// and `normal` is the normal of the root cell's hyperplane.
+fn build_cell(
+    complex: &BoundaryComplex,
+    cell: u32,
+    parent: Option<u32>,
+    hinge: Option<u32>,
+    parent_linear: &Matrix4<f64>,
+    normal: &Vector4<f64>,
+) -> NetCell {
+    let vertices = complex.get_vertices_of_cell(cell);
+    let edges = complex
+        .get_edges_of_cell(cell)
+        .iter()
+        .map(|edge| {
+            let [a, b] = complex.get_edge(*edge);
+            let local = |vertex: u32| vertices.binary_search(&vertex).unwrap() as u32;
+            [local(a), local(b)]
+        })
+        .collect();
+    let centroid = complex.get_cell_centroid(cell);
+
+    let (pivot, plane, angle) = match (parent, hinge) {
+        (Some(_), Some(hinge)) => {
+            let face = complex.get_face(hinge);
+            let pivot = face.iter().fold(Vector4::zero(), |sum, vertex| {
+                sum + get_vertex(complex, *vertex)
+            }) / face.len() as f64;
+
+            // Rotate this cell about the hinge until its (outward-facing) normal matches
+            // the normal of the root cell, once its parent has been unfolded. The rotation
+            // is found in the parent's unfolded frame, so that any (small) error in the
+            // parent's fold isn't passed on to all of its descendants.
+            let cell_normal = parent_linear * get_cell_normal(complex, cell);
+            let mut other = cell_normal - normal * normal.dot(cell_normal);
+            if other.magnitude() > 1.0e-6 {
+                other = other.normalize();
+            }
+            let angle = other.dot(cell_normal).atan2(normal.dot(cell_normal));
+
+            // The plane of rotation is expressed in the parent's original frame: the
+            // inverse of a rotation is its transpose.
+            let inverse = parent_linear.transpose();
+            (pivot, [inverse * normal, inverse * other], angle)
+        }
+        _ => (Vector4::zero(), [Vector4::zero(); 2], 0.0),
+    };
+
+    NetCell {
+        parent,
+        hinge,
+        pivot,
+        plane,
+        angle,
+        vertices,
+        edges,
+        centroid,
+    }
+}
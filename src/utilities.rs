@@ -1,15 +1,14 @@
 use std::fs::File;
 use std::io::Read;
-use std::iter;
+#[cfg(feature = "rendering")]
 use std::os::raw::c_void;
 use std::path::Path;
 
-use cgmath::{self, ElementWise, Vector3, Vector4};
+use cgmath::{ElementWise, Vector3, Vector4};
+#[cfg(feature = "rendering")]
 use gl;
-use gl::types::*;
-use image::{self, GenericImage, ImageBuffer};
-
-use program::Program;
+#[cfg(feature = "rendering")]
+use image;
 
 /// Creates an RGBA color (represented as a vector) from a hex code and alpha.
 /// For example, `from_hex(0xffffff, 0.5)` would return the vector `<1.0, 1.0, 1.0, 0.5>`.
@@ -68,7 +67,6 @@ pub fn index_of_largest(v: &Vector4<f32>) -> usize {
         largest_index = 2;
     }
     if v.w.abs() > largest_val {
-        largest_val = v.w.abs();
         largest_index = 3;
     }
 
@@ -77,12 +75,12 @@ pub fn index_of_largest(v: &Vector4<f32>) -> usize {
 
 /// Clamps `value` so that it lies in the range `0.0 .. 1.0`.
 pub fn saturate(value: f32) -> f32 {
-    value.min(1.0).max(0.0)
+    value.clamp(0.0, 1.0)
 }
 
 /// Clamps `value` so that it lies in the range `min .. max`.
 pub fn saturate_between(value: f32, min: f32, max: f32) -> f32 {
-    value.min(max).max(min)
+    value.clamp(min, max)
 }
 
 use std::ops::{Add, Div};
@@ -107,9 +105,9 @@ pub fn load_file_as_string(path: &Path) -> String {
 }
 
 /// Saves the current frame to disk at `path` with dimensions `width`x`height`.
+#[cfg(feature = "rendering")]
 pub fn save_frame(path: &Path, width: u32, height: u32) {
-    let mut pixels: Vec<u8> = Vec::new();
-    pixels.reserve((width * height * 3) as usize);
+    let mut pixels: Vec<u8> = vec![0; (width * height * 3) as usize];
 
     unsafe {
         // We don't want any alignment padding on pixel rows.
@@ -123,7 +121,6 @@ pub fn save_frame(path: &Path, width: u32, height: u32) {
             gl::UNSIGNED_BYTE,
             pixels.as_mut_ptr() as *mut c_void,
         );
-        pixels.set_len((width * height * 3) as usize);
     }
 
     image::save_buffer(path, &pixels, width, height, image::RGB(8)).unwrap();
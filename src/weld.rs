@@ -0,0 +1,454 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use cgmath::{InnerSpace, Vector3, Vector4};
+
+use hyperplane::Hyperplane;
+use math;
+use tetrahedron::{Slice, Tetrahedron};
+
+/// Merges vertices that are (almost) coincident. Vertices are bucketed in a uniform grid,
+/// so that only the vertices in neighboring buckets need to be compared.
+pub struct Welder {
+    vertices: Vec<Vector4<f32>>,
+    buckets: HashMap<[i64; 4], Vec<u32>>,
+}
+
+impl Welder {
+    /// Vertices that are closer than this are merged.
+    const TOLERANCE: f32 = 1.0e-5;
+
+    /// The size of each bucket, which must be larger than `TOLERANCE`.
+    const BUCKET_SIZE: f32 = 1.0e-4;
+
+    pub fn new() -> Welder {
+        Welder {
+            vertices: Vec::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    fn get_bucket(vertex: &Vector4<f32>) -> [i64; 4] {
+        [
+            (vertex.x / Welder::BUCKET_SIZE).floor() as i64,
+            (vertex.y / Welder::BUCKET_SIZE).floor() as i64,
+            (vertex.z / Welder::BUCKET_SIZE).floor() as i64,
+            (vertex.w / Welder::BUCKET_SIZE).floor() as i64,
+        ]
+    }
+
+    /// Returns the index of the (welded) vertex at `vertex`, adding it if necessary.
+    pub fn insert(&mut self, vertex: Vector4<f32>) -> u32 {
+        let bucket = Welder::get_bucket(&vertex);
+
+        for neighbor in 0..81 {
+            // Visit each of the 3^4 neighboring buckets (including this one).
+            let mut key = bucket;
+            let mut offset = neighbor;
+            for component in key.iter_mut() {
+                *component += offset % 3 - 1;
+                offset /= 3;
+            }
+
+            if let Some(indices) = self.buckets.get(&key) {
+                for index in indices.iter() {
+                    if (self.vertices[*index as usize] - vertex).magnitude() <= Welder::TOLERANCE {
+                        return *index;
+                    }
+                }
+            }
+        }
+
+        let index = self.vertices.len() as u32;
+        self.vertices.push(vertex);
+        self.buckets.entry(bucket).or_default().push(index);
+        index
+    }
+
+    /// Returns all of the (unique) vertices that have been inserted so far.
+    pub fn get_vertices(&self) -> &Vec<Vector4<f32>> {
+        &self.vertices
+    }
+
+    /// Consumes the welder, returning all of the (unique) vertices.
+    pub fn into_vertices(self) -> Vec<Vector4<f32>> {
+        self.vertices
+    }
+}
+
+impl Default for Welder {
+    fn default() -> Welder {
+        Welder::new()
+    }
+}
+
+/// Makes the winding order of a set of polygonal `faces` (each of which is a list of
+/// indices into `positions`) consistent, so that all of their normals face outwards.
+/// The slicing procedure itself produces polygons with arbitrary winding orders.
+///
+/// Starting from any face, its neighbors are flipped (as necessary) so that they
+/// traverse each shared edge in the opposite direction. Then, any connected component
+/// with a negative signed volume is inside-out, so all of its faces are flipped.
+pub fn orient<F>(positions: &[Vector3<f32>], faces: &mut [F])
+where
+    F: AsRef<[u32]> + AsMut<[u32]>,
+{
+    let get_edges = |face: &[u32]| {
+        (0..face.len())
+            .map(|k| (face[k], face[(k + 1) % face.len()]))
+            .collect::<Vec<_>>()
+    };
+
+    let mut neighbors: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for (a, b) in get_edges(face.as_ref()) {
+            neighbors.entry((a.min(b), a.max(b))).or_default().push(i);
+        }
+    }
+
+    let mut visited = vec![false; faces.len()];
+    for start in 0..faces.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        let mut component = vec![start];
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        while let Some(current) = queue.pop_front() {
+            for (a, b) in get_edges(faces[current].as_ref()) {
+                for neighbor in neighbors[&(a.min(b), a.max(b))].iter() {
+                    if visited[*neighbor] {
+                        continue;
+                    }
+                    visited[*neighbor] = true;
+
+                    // A consistently oriented neighbor traverses this edge as `b -> a`.
+                    if get_edges(faces[*neighbor].as_ref()).contains(&(a, b)) {
+                        faces[*neighbor].as_mut().reverse();
+                    }
+                    component.push(*neighbor);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+
+        // Compute the signed volume of this component by fanning out each of its faces.
+        let signed_volume = component
+            .iter()
+            .map(|i| {
+                let face = faces[*i].as_ref();
+                let first = positions[face[0] as usize];
+                (1..face.len() - 1)
+                    .map(|k| {
+                        first
+                            .dot(positions[face[k] as usize].cross(positions[face[k + 1] as usize]))
+                    })
+                    .sum::<f32>()
+            })
+            .sum::<f32>();
+        if signed_volume < 0.0 {
+            for i in component.iter() {
+                faces[*i].as_mut().reverse();
+            }
+        }
+    }
+}
+
+/// A closed polyhedron that is part of a 3-dimensional cross-section of a mesh. Each of
+/// its faces is the (polygonal) slice of a single cell of the mesh.
+///
+/// The points where the hyperplane crosses the interior edges of a cell's tetrahedral
+/// decomposition are removed, so the vertices of each face are its corners.
+pub struct SlicePolyhedron {
+    /// The unique vertices of the polyhedron, which lie in the slicing hyperplane
+    vertices: Vec<Vector4<f32>>,
+
+    /// The faces of the polyhedron, each of which is a closed loop of vertex indices,
+    /// ordered so that its normal faces outwards
+    faces: Vec<Vec<u32>>,
+
+    /// The index of the cell that each face is a slice of
+    cell_indices: Vec<u32>,
+}
+
+impl SlicePolyhedron {
+    /// Returns the unique vertices of this polyhedron.
+    pub fn get_vertices(&self) -> &Vec<Vector4<f32>> {
+        &self.vertices
+    }
+
+    /// Returns the faces of this polyhedron, each of which is a closed loop of indices
+    /// into `get_vertices(...)`.
+    pub fn get_faces(&self) -> &Vec<Vec<u32>> {
+        &self.faces
+    }
+
+    /// Returns the index of the cell that each face is a slice of.
+    pub fn get_cell_indices(&self) -> &Vec<u32> {
+        &self.cell_indices
+    }
+
+    /// Returns the edges of this polyhedron, as pairs of vertex indices. These are the
+    /// outlines of each of the cell slices, which is useful for drawing wireframes.
+    pub fn get_edges(&self) -> Vec<u32> {
+        let mut edges = self
+            .faces
+            .iter()
+            .flat_map(|face| {
+                (0..face.len()).map(move |k| {
+                    let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                    (a.min(b), a.max(b))
+                })
+            })
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges.dedup();
+
+        edges.iter().flat_map(|(a, b)| vec![*a, *b]).collect()
+    }
+}
+
+/// Chains a set of (undirected) boundary edges into closed loops of vertex indices.
+fn chain_loops(edges: &[(u32, u32)]) -> Vec<Vec<u32>> {
+    let mut adjacency: BTreeMap<u32, Vec<(u32, usize)>> = BTreeMap::new();
+    for (i, (a, b)) in edges.iter().enumerate() {
+        adjacency.entry(*a).or_default().push((*b, i));
+        adjacency.entry(*b).or_default().push((*a, i));
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut loops = Vec::new();
+
+    for (i, (start, next)) in edges.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+
+        let mut polygon = vec![*start];
+        let mut current = *next;
+        while current != *start {
+            polygon.push(current);
+
+            let step = adjacency[&current]
+                .iter()
+                .find(|(_, edge)| !used[*edge])
+                .cloned();
+            match step {
+                Some((vertex, edge)) => {
+                    used[edge] = true;
+                    current = vertex;
+                }
+                // The boundary isn't closed (this shouldn't happen), so discard this chain.
+                None => {
+                    polygon.clear();
+                    break;
+                }
+            }
+        }
+
+        if polygon.len() >= 3 {
+            loops.push(polygon);
+        }
+    }
+
+    loops
+}
+
+/// Removes the vertices of a closed `polygon` (a loop of indices into `positions`) that
+/// lie on the line between their neighbors, i.e. where the hyperplane crosses the edges
+/// of the tetrahedral decompositions of the cells. Returns `None` if fewer than 3 vertices
+/// remain, in which case the polygon doesn't enclose any area.
+fn merge_collinear(polygon: &[u32], positions: &[Vector3<f32>]) -> Option<Vec<u32>> {
+    let mut polygon = polygon.to_vec();
+
+    let mut k = 0;
+    while polygon.len() >= 3 && k < polygon.len() {
+        let previous = polygon[(k + polygon.len() - 1) % polygon.len()];
+        let next = polygon[(k + 1) % polygon.len()];
+        let triangle = [previous, polygon[k], next];
+
+        if is_degenerate(&triangle, positions) {
+            polygon.remove(k);
+            // The previous vertex might have become collinear with its new neighbor.
+            k = k.saturating_sub(1);
+        } else {
+            k += 1;
+        }
+    }
+
+    if polygon.len() >= 3 {
+        Some(polygon)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if the `triangle` (of indices into `positions`) has (almost) no area:
+/// that is, if one of its vertices is within `Welder::TOLERANCE` of the line through the
+/// other two.
+fn is_degenerate(triangle: &[u32; 3], positions: &[Vector3<f32>]) -> bool {
+    let [a, b, c] = [
+        positions[triangle[0] as usize],
+        positions[triangle[1] as usize],
+        positions[triangle[2] as usize],
+    ];
+    let longest = (b - a)
+        .magnitude()
+        .max((c - b).magnitude())
+        .max((a - c).magnitude());
+
+    // Twice the area, divided by the longest side, is the smallest height.
+    longest <= Welder::TOLERANCE || (b - a).cross(c - a).magnitude() / longest <= Welder::TOLERANCE
+}
+
+/// A post-pass that turns the "triangle soup" produced by slicing each of the `tetrahedra`
+/// of a mesh (see `Mesh::slice(...)`) into a set of closed polyhedra, which together
+/// make up the cross-section of the mesh with `hyperplane`.
+///
+/// The slices of all of the tetrahedra that belong to the same cell are welded together
+/// (merging coincident vertices), and edges that are shared by two triangles of the same
+/// cell are dropped. What remains is the outline of each cell's slice, which is a polygon.
+/// Finally, these polygons are grouped into connected, closed polyhedra.
+///
+/// Hyperplanes that pass through the vertices of the mesh need some extra care. Triangles
+/// that have no area (i.e. where the hyperplane only touches an edge of a tetrahedron) are
+/// dropped. If the hyperplane contains a face of the mesh, both of the cells that share that
+/// face produce the same polygon, so only one copy of it is kept.
+pub fn weld_slices(
+    tetrahedra: &[Tetrahedron],
+    slices: &[Slice],
+    hyperplane: &Hyperplane,
+) -> Vec<SlicePolyhedron> {
+    let mut welder = Welder::new();
+
+    // Vertices are compared based on their positions within the hyperplane.
+    let basis = math::get_hyperplane_basis(&hyperplane.normal);
+    let to_3d = |vertex: &Vector4<f32>| {
+        Vector3::new(
+            vertex.dot(basis[0]),
+            vertex.dot(basis[1]),
+            vertex.dot(basis[2]),
+        )
+    };
+    let mut positions = Vec::new();
+
+    // Gather the (welded) triangles of each cell.
+    let mut triangles_per_cell: BTreeMap<u32, Vec<[u32; 3]>> = BTreeMap::new();
+    for (tetrahedron, slice) in tetrahedra.iter().zip(slices.iter()) {
+        for triangle in slice.get_triangles().iter() {
+            let mut indices = [
+                welder.insert(triangle[0]),
+                welder.insert(triangle[1]),
+                welder.insert(triangle[2]),
+            ];
+            indices.sort();
+            positions.extend(welder.get_vertices()[positions.len()..].iter().map(&to_3d));
+
+            // Skip triangles that collapsed after welding.
+            if !is_degenerate(&indices, &positions) {
+                triangles_per_cell
+                    .entry(tetrahedron.cell_index)
+                    .or_default()
+                    .push(indices);
+            }
+        }
+    }
+
+    let mut faces = Vec::new();
+    let mut cell_indices = Vec::new();
+    let mut unique_faces = BTreeSet::new();
+    for (cell_index, triangles) in triangles_per_cell.iter_mut() {
+        // If the hyperplane passes through a face that is shared by two tetrahedra of
+        // this cell, both of them will produce the same triangle: keep one of them.
+        triangles.sort();
+        triangles.dedup();
+
+        // Edges that are shared by two triangles are inside of the cell's slice, so only
+        // the edges on its boundary are kept.
+        let mut edge_counts: BTreeMap<(u32, u32), usize> = BTreeMap::new();
+        for triangle in triangles.iter() {
+            for (a, b) in [
+                (triangle[0], triangle[1]),
+                (triangle[1], triangle[2]),
+                (triangle[0], triangle[2]),
+            ]
+            .iter()
+            {
+                *edge_counts.entry((*a, *b)).or_insert(0) += 1;
+            }
+        }
+        let boundary = edge_counts
+            .iter()
+            .filter(|(_, count)| **count == 1)
+            .map(|(edge, _)| *edge)
+            .collect::<Vec<_>>();
+
+        for polygon in chain_loops(&boundary) {
+            if let Some(polygon) = merge_collinear(&polygon, &positions) {
+                // Cells that share a face that lies in the hyperplane produce the same
+                // polygon.
+                let mut key = polygon.clone();
+                key.sort();
+                if unique_faces.insert(key) {
+                    faces.push(polygon);
+                    cell_indices.push(*cell_index);
+                }
+            }
+        }
+    }
+
+    // Orient the faces, based on their positions within the hyperplane.
+    orient(&positions, &mut faces);
+
+    // Group the faces into connected components (faces are connected if they share a
+    // vertex), each of which becomes its own polyhedron.
+    let vertices = welder.into_vertices();
+    let mut parents = (0..vertices.len()).collect::<Vec<_>>();
+    fn find(parents: &mut [usize], i: usize) -> usize {
+        let mut root = i;
+        while parents[root] != root {
+            root = parents[root];
+        }
+        parents[i] = root;
+        root
+    }
+    for face in faces.iter() {
+        for vertex in face.iter().skip(1) {
+            let (a, b) = (
+                find(&mut parents, face[0] as usize),
+                find(&mut parents, *vertex as usize),
+            );
+            parents[a] = b;
+        }
+    }
+
+    let mut components: BTreeMap<usize, SlicePolyhedron> = BTreeMap::new();
+    let mut remapped: HashMap<u32, u32> = HashMap::new();
+    for (face, cell_index) in faces.iter().zip(cell_indices.iter()) {
+        let root = find(&mut parents, face[0] as usize);
+        let polyhedron = components.entry(root).or_insert_with(|| SlicePolyhedron {
+            vertices: Vec::new(),
+            faces: Vec::new(),
+            cell_indices: Vec::new(),
+        });
+
+        // Each vertex belongs to exactly one component, so the mapping from welded
+        // vertices to component vertices can be shared.
+        let face = face
+            .iter()
+            .map(|vertex| {
+                *remapped.entry(*vertex).or_insert_with(|| {
+                    polyhedron.vertices.push(vertices[*vertex as usize]);
+                    polyhedron.vertices.len() as u32 - 1
+                })
+            })
+            .collect::<Vec<_>>();
+        polyhedron.faces.push(face);
+        polyhedron.cell_indices.push(*cell_index);
+    }
+
+    components.into_values().collect()
+}
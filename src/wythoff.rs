@@ -0,0 +1,426 @@
+use std::collections::BTreeSet;
+use std::f64;
+
+use cgmath::{InnerSpace, Vector4};
+
+use hyperplane::Hyperplane;
+use math;
+use polychora::{Definition, Polytope};
+use utilities;
+use weld::Welder;
+
+/// An enumeration representing the finite, irreducible Coxeter groups of rank 4, each
+/// of which is the symmetry group of one or more of the regular polychora.
+///
+/// The nodes of each Coxeter-Dynkin diagram are numbered as follows:
+///
+/// ```text
+/// A4, B4, F4, H4:    0 --- 1 --- 2 --- 3
+///
+/// D4:                0 --- 1 --- 2
+///                          |
+///                          3
+/// ```
+///
+/// Reference: `https://en.wikipedia.org/wiki/Coxeter%E2%80%93Dynkin_diagram`
+#[derive(Copy, Clone, Debug)]
+pub enum CoxeterGroup {
+    /// The symmetry group [3, 3, 3] of the 5-cell
+    A4,
+
+    /// The symmetry group [4, 3, 3] of the 8-cell and 16-cell
+    B4,
+
+    /// The symmetry group [3^1,1,1] of the demitesseract (which is another 16-cell)
+    D4,
+
+    /// The symmetry group [3, 4, 3] of the 24-cell
+    F4,
+
+    /// The symmetry group [5, 3, 3] of the 120-cell and 600-cell
+    H4,
+}
+
+impl CoxeterGroup {
+    /// Returns the order `m` of the product of the reflections through mirrors `i` and
+    /// `j`: the two mirrors meet at an angle of `π / m`. Nodes that are not connected in
+    /// the diagram have `m = 2` (perpendicular mirrors).
+    fn get_order(&self, i: usize, j: usize) -> u32 {
+        if i == j {
+            return 1;
+        }
+        let (i, j) = (i.min(j), i.max(j));
+
+        match *self {
+            CoxeterGroup::D4 => match (i, j) {
+                (0, 1) | (1, 2) | (1, 3) => 3,
+                _ => 2,
+            },
+            _ => {
+                if j != i + 1 {
+                    return 2;
+                }
+                let schlafli = match *self {
+                    CoxeterGroup::A4 => [3, 3, 3],
+                    CoxeterGroup::B4 => [4, 3, 3],
+                    CoxeterGroup::F4 => [3, 4, 3],
+                    CoxeterGroup::H4 => [5, 3, 3],
+                    CoxeterGroup::D4 => unreachable!(),
+                };
+                schlafli[i]
+            }
+        }
+    }
+
+    /// Returns the unit normals of the 4 mirrors that generate this group (the "simple
+    /// roots"), such that the angle between mirrors `i` and `j` is `π / m_ij`.
+    ///
+    /// These are found via a Cholesky decomposition of the Gram matrix of the roots,
+    /// whose entries are `-cos(π / m_ij)`: the rows of the resulting lower triangular
+    /// matrix are the roots themselves.
+    fn get_roots(&self) -> [Vector4<f64>; 4] {
+        let mut gram = [[0.0; 4]; 4];
+        for (i, row) in gram.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = -(f64::consts::PI / f64::from(self.get_order(i, j))).cos();
+            }
+        }
+
+        let mut lower = [[0.0; 4]; 4];
+        for i in 0..4 {
+            for j in 0..=i {
+                let sum = (0..j).map(|k| lower[i][k] * lower[j][k]).sum::<f64>();
+                if i == j {
+                    lower[i][j] = (gram[i][i] - sum).sqrt();
+                } else {
+                    lower[i][j] = (gram[i][j] - sum) / lower[j][j];
+                }
+            }
+        }
+
+        [
+            Vector4::from(lower[0]),
+            Vector4::from(lower[1]),
+            Vector4::from(lower[2]),
+            Vector4::from(lower[3]),
+        ]
+    }
+
+    /// Returns `true` if every connected component of the sub-diagram made up of `nodes`
+    /// contains at least one ringed node. This is exactly the condition under which the
+    /// orbit of the generating vertex under the corresponding subgroup spans a face of
+    /// dimension `nodes.len()`.
+    fn is_active(&self, nodes: &[usize], rings: &[bool; 4]) -> bool {
+        let mut unvisited = nodes.to_vec();
+
+        while let Some(start) = unvisited.pop() {
+            let mut component = vec![start];
+            let mut ringed = false;
+
+            while let Some(node) = component.pop() {
+                ringed |= rings[node];
+
+                let (connected, rest): (Vec<usize>, Vec<usize>) = unvisited
+                    .iter()
+                    .partition(|other| self.get_order(node, **other) > 2);
+                component.extend(connected);
+                unvisited = rest;
+            }
+
+            if !ringed {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Reflects `v` through the mirror (passing through the origin) with unit normal `root`.
+fn reflect(v: &Vector4<f64>, root: &Vector4<f64>) -> Vector4<f64> {
+    v - root * (2.0 * root.dot(*v))
+}
+
+/// Given a table `permutations` that maps each vertex to its mirror image through each
+/// of the 4 generating mirrors, returns every distinct image of the (sorted) set of vertex
+/// indices `seed` under the subgroup generated by the mirrors in `generators`.
+fn orbit(seed: Vec<u32>, permutations: &[[u32; 4]], generators: &[usize]) -> Vec<Vec<u32>> {
+    let mut seen = BTreeSet::new();
+    let mut orbit = Vec::new();
+    let mut queue = vec![seed];
+
+    while let Some(set) = queue.pop() {
+        if !seen.insert(set.clone()) {
+            continue;
+        }
+
+        for generator in generators.iter() {
+            let mut image = set
+                .iter()
+                .map(|index| permutations[*index as usize][*generator])
+                .collect::<Vec<_>>();
+            image.sort();
+            queue.push(image);
+        }
+        orbit.push(set);
+    }
+
+    orbit
+}
+
+/// A uniform polychoron, generated via Wythoff's kaleidoscopic construction.
+///
+/// A Coxeter group is generated by reflections through 4 mirrors. Placing a single point
+/// inside of the region bounded by the mirrors, at a distance of 1 from each "ringed"
+/// mirror and on each of the "unringed" mirrors, and then reflecting it over and over
+/// again produces the vertices of a uniform polychoron. The faces and cells of the
+/// polychoron are the orbits of that same point under the subgroups generated by pairs
+/// and triples of mirrors, respectively.
+///
+/// For example, with the `B4` group (nodes numbered as in `CoxeterGroup`):
+///
+/// ```text
+/// [x, o, o, o]: 8-cell              [o, o, o, x]: 16-cell
+/// [x, x, o, o]: truncated 8-cell    [o, x, o, o]: rectified 8-cell
+/// [x, o, x, o]: cantellated 8-cell  [x, o, o, x]: runcinated 8-cell
+/// [o, x, x, o]: bitruncated 8-cell  [x, x, x, x]: omnitruncated 8-cell
+/// ```
+///
+/// All vertices are scaled to have unit length, like the built-in polychora.
+///
+/// Reference: `https://en.wikipedia.org/wiki/Wythoff_construction`
+pub struct UniformPolychoron {
+    /// The vertices of the polychoron
+    vertices: Vec<Vector4<f32>>,
+
+    /// The edges of the polychoron, as pairs of vertex indices
+    edges: Vec<u32>,
+
+    /// The faces of the polychoron, each of which is a list of vertex indices (in order)
+    faces: Vec<Vec<u32>>,
+
+    /// The cells of the polychoron, each of which is a list of face indices
+    cells: Vec<Vec<u32>>,
+
+    /// The hyperplanes that bound each of the cells of the polychoron
+    h_representation: Vec<Hyperplane>,
+}
+
+impl UniformPolychoron {
+    /// Generates the uniform polychoron with symmetry `group`, where `rings[i]` is `true`
+    /// if the `i`th node of the group's Coxeter-Dynkin diagram is ringed.
+    pub fn new(group: CoxeterGroup, rings: [bool; 4]) -> UniformPolychoron {
+        assert!(
+            rings.iter().any(|ring| *ring),
+            "At least one node of the Coxeter-Dynkin diagram must be ringed"
+        );
+
+        // Find the generating vertex by solving `roots[i].dot(v) = 1` (ringed) or `0`
+        // (unringed): since the roots form a lower triangular matrix, this is just
+        // forward substitution.
+        let roots = group.get_roots();
+        let mut generator = Vector4::new(0.0, 0.0, 0.0, 0.0);
+        for i in 0..4 {
+            let target = if rings[i] { 1.0 } else { 0.0 };
+            let sum = (0..i).map(|k| roots[i][k] * generator[k]).sum::<f64>();
+            generator[i] = (target - sum) / roots[i][i];
+        }
+
+        // Reflect the generating vertex until no new vertices are found, keeping track of
+        // where each vertex ends up under each of the 4 reflections. The reflections are
+        // computed in double precision, but vertices are identified by welding their
+        // (normalized) positions: two images of the same vertex will have accumulated
+        // slightly different rounding errors.
+        let radius = generator.magnitude();
+        let normalize = |p: &Vector4<f64>| {
+            let p = p / radius;
+            Vector4::new(p.x as f32, p.y as f32, p.z as f32, p.w as f32)
+        };
+
+        let mut positions = vec![generator];
+        let mut welder = Welder::new();
+        welder.insert(normalize(&generator));
+        let mut permutations: Vec<[u32; 4]> = Vec::new();
+
+        let mut current = 0;
+        while current < positions.len() {
+            let mut images = [0; 4];
+            for (image, root) in images.iter_mut().zip(roots.iter()) {
+                let reflected = reflect(&positions[current], root);
+                *image = welder.insert(normalize(&reflected));
+                if *image as usize == positions.len() {
+                    positions.push(reflected);
+                }
+            }
+            permutations.push(images);
+            current += 1;
+        }
+
+        let vertices = welder.into_vertices();
+
+        let all = [0, 1, 2, 3];
+
+        // Each ringed mirror produces an edge between the generating vertex and its image.
+        let mut edges = Vec::new();
+        for node in all.iter().filter(|node| rings[**node]) {
+            let mut seed = vec![0, permutations[0][*node]];
+            seed.sort();
+            for edge in orbit(seed, &permutations, &all).iter() {
+                edges.extend_from_slice(edge);
+            }
+        }
+
+        // Each "active" pair of mirrors produces a family of polygonal faces.
+        let mut faces = Vec::new();
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                if !group.is_active(&[i, j], &rings) {
+                    continue;
+                }
+                let polygon = orbit(vec![0], &permutations, &[i, j])
+                    .iter()
+                    .map(|set| set[0])
+                    .collect::<Vec<_>>();
+
+                let mut seed = polygon.clone();
+                seed.sort();
+                for face in orbit(seed, &permutations, &all).iter() {
+                    // Sort the vertices of each face so that they form a closed polygon.
+                    let face_vertices = face
+                        .iter()
+                        .map(|index| vertices[*index as usize])
+                        .collect::<Vec<_>>();
+                    faces.push(
+                        math::sort_indices_on_plane(&face_vertices)
+                            .iter()
+                            .map(|k| face[*k])
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+        }
+
+        // Each "active" triple of mirrors produces a family of polyhedral cells.
+        let mut cell_vertices = Vec::new();
+        for omitted in 0..4 {
+            let nodes = all
+                .iter()
+                .cloned()
+                .filter(|node| *node != omitted)
+                .collect::<Vec<_>>();
+            if !group.is_active(&nodes, &rings) {
+                continue;
+            }
+            let mut seed = orbit(vec![0], &permutations, &nodes)
+                .iter()
+                .map(|set| set[0])
+                .collect::<Vec<_>>();
+            seed.sort();
+            cell_vertices.extend(orbit(seed, &permutations, &all));
+        }
+
+        // Find the faces that belong to each cell: these are the faces whose vertices
+        // are all vertices of the cell.
+        let mut faces_around_vertex = vec![Vec::new(); vertices.len()];
+        for (face_index, face) in faces.iter().enumerate() {
+            for index in face.iter() {
+                faces_around_vertex[*index as usize].push(face_index as u32);
+            }
+        }
+
+        let mut cells = Vec::new();
+        let mut h_representation = Vec::new();
+        for cell in cell_vertices.iter() {
+            let mut cell_faces = cell
+                .iter()
+                .flat_map(|index| faces_around_vertex[*index as usize].iter().cloned())
+                .filter(|face_index| {
+                    faces[*face_index as usize]
+                        .iter()
+                        .all(|index| cell.binary_search(index).is_ok())
+                })
+                .collect::<Vec<_>>();
+            cell_faces.sort();
+            cell_faces.dedup();
+            cells.push(cell_faces);
+
+            h_representation.push(get_cell_hyperplane(
+                &cell
+                    .iter()
+                    .map(|index| vertices[*index as usize])
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        UniformPolychoron {
+            vertices,
+            edges,
+            faces,
+            cells,
+            h_representation,
+        }
+    }
+
+    /// Returns a list of the cells of this polychoron, where each cell is a list of indices
+    /// into the array returned by `get_faces(...)`. The `i`th cell lies in the `i`th
+    /// hyperplane of the polychoron's H-representation.
+    pub fn get_cells(&self) -> Vec<Vec<u32>> {
+        self.cells.clone()
+    }
+}
+
+/// Returns the hyperplane that contains all of the `points` that make up a cell, with a
+/// normal that faces away from the origin.
+fn get_cell_hyperplane(points: &[Vector4<f32>]) -> Hyperplane {
+    let centroid = utilities::average(points, &Vector4::new(0.0, 0.0, 0.0, 0.0));
+
+    // Pick 3 directions (from the first point) that span the cell: each one is the
+    // direction that is "most" orthogonal to the ones chosen so far.
+    let mut basis: Vec<Vector4<f32>> = Vec::new();
+    let mut spanning = Vec::new();
+    for _ in 0..3 {
+        let (direction, residual) = points
+            .iter()
+            .map(|point| {
+                let direction = point - points[0];
+                let mut residual = direction;
+                for axis in basis.iter() {
+                    residual -= axis * axis.dot(residual);
+                }
+                (direction, residual)
+            })
+            .max_by(|a, b| a.1.magnitude2().total_cmp(&b.1.magnitude2()))
+            .unwrap();
+        basis.push(residual.normalize());
+        spanning.push(direction);
+    }
+
+    let mut normal = math::cross(&spanning[0], &spanning[1], &spanning[2]).normalize();
+    if normal.dot(centroid) < 0.0 {
+        normal = -normal;
+    }
+
+    Hyperplane::new(normal, -normal.dot(centroid))
+}
+
+impl Polytope for UniformPolychoron {
+    fn get_definition(&self) -> Definition {
+        Definition::from_topology(&self.faces, &self.cells)
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        self.vertices.clone()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        self.edges.clone()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
@@ -0,0 +1,168 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::{EuclideanSpace, InnerSpace, Matrix4, Point3, Transform, Vector3, Vector4, Zero};
+
+use four::camera::{Camera, FourCamera, ThreeCamera, View};
+use four::constants;
+use four::math::Plane;
+
+/// Returns the default 4D camera that is used by the application.
+fn four_camera() -> FourCamera {
+    FourCamera::new(
+        Vector4::unit_x() * 1.5,
+        Vector4::zero(),
+        Vector4::unit_y(),
+        Vector4::unit_z(),
+    )
+}
+
+/// Returns the default 3D camera that is used by the application.
+fn three_camera() -> ThreeCamera {
+    ThreeCamera::new(
+        Point3::new(0.0, 0.5, 8.5),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    )
+}
+
+/// Checks that the columns of `matrix` form an orthonormal basis.
+fn assert_orthonormal(matrix: &Matrix4<f32>) {
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((matrix[i].dot(matrix[j]) - expected).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn orbit_keeps_distance() {
+    let mut camera = four_camera();
+
+    for i in 0..40 {
+        let plane = match i % 4 {
+            0 => Plane::XW,
+            1 => Plane::YW,
+            2 => Plane::ZW,
+            _ => Plane::XY,
+        };
+
+        let from = camera.get_from();
+        camera.orbit_in_plane(plane, 0.1 + i as f32 * 0.05);
+
+        assert!((camera.get_distance() - 1.5).abs() < 1e-4);
+        assert_eq!(camera.get_to(), Vector4::zero());
+        assert_orthonormal(camera.get_look_at());
+
+        // The eye starts on the x-axis, so the first rotation (in the `XW` plane) moves it.
+        if i == 0 {
+            assert!((camera.get_from() - from).magnitude() > 1e-3);
+        }
+    }
+}
+
+#[test]
+fn fov_is_clamped() {
+    let mut camera = four_camera();
+
+    camera.set_fov(1.0);
+    assert_eq!(camera.get_fov(), 1.0);
+
+    camera.set_fov(0.0);
+    assert!(camera.get_fov() > 0.0);
+
+    camera.set_fov(10.0);
+    assert!(camera.get_fov() < std::f32::consts::PI);
+
+    // The projection shouldn't degenerate at either end of the range.
+    for fov in [-1.0, 0.0, 10.0].iter() {
+        camera.set_fov(*fov);
+        let scale = camera.get_projection()[0][0];
+        assert!(scale.is_finite() && scale > 0.0);
+    }
+}
+
+#[test]
+fn set_distance_moves_eye_along_view() {
+    let mut camera = four_camera();
+
+    camera.set_distance(3.0);
+    assert!((camera.get_from() - Vector4::unit_x() * 3.0).magnitude() < 1e-6);
+
+    // The eye never reaches the target.
+    camera.set_distance(-1.0);
+    assert!(camera.get_distance() > 0.0);
+    assert!(camera.get_from().x > 0.0);
+}
+
+#[test]
+fn look_along_each_axis() {
+    let mut camera = four_camera();
+    let default = *camera.get_look_at();
+
+    for axis in 0..4 {
+        camera.look_along_axis(axis);
+
+        let mut direction = Vector4::zero();
+        direction[axis] = 1.0;
+        assert!((camera.get_from() - direction * 1.5).magnitude() < 1e-6);
+        assert!((camera.get_look_at()[3] + direction).magnitude() < 1e-6);
+        assert_orthonormal(camera.get_look_at());
+    }
+
+    // Looking along the x-axis is the default view.
+    camera.look_along_axis(0);
+    assert_eq!(*camera.get_look_at(), default);
+}
+
+#[test]
+fn top_view_looks_down_y_axis() {
+    let mut camera = three_camera();
+    let distance = camera.get_from().to_vec().magnitude();
+
+    camera.set_view(View::Top);
+    assert!((camera.get_from() - Point3::new(0.0, distance, 0.0)).magnitude() < 1e-4);
+
+    // In view space, the camera looks down the negative z-axis: the target should be
+    // straight ahead, and the world's negative z-axis should point up.
+    let look_at = camera.get_look_at();
+    let target = look_at.transform_point(Point3::new(0.0, 0.0, 0.0));
+    assert!((target - Point3::new(0.0, 0.0, -distance)).magnitude() < 1e-4);
+    let up = look_at.transform_vector(-Vector3::unit_z());
+    assert!((up - Vector3::unit_y()).magnitude() < 1e-4);
+    let ahead = look_at.transform_vector(-Vector3::unit_y());
+    assert!((ahead + Vector3::unit_z()).magnitude() < 1e-4);
+}
+
+#[test]
+fn zoom_scales_orthographic_extents() {
+    let mut camera = three_camera();
+    camera.set_orthographic(true);
+    let (from, scale) = (camera.get_from(), camera.get_scale());
+
+    // The projection maps `±scale` (vertically) to the edges of the screen.
+    camera.zoom(0.5);
+    assert!((camera.get_scale() - (scale - 0.5)).abs() < 1e-6);
+    assert!((camera.get_projection()[1][1] * camera.get_scale() - 1.0).abs() < 1e-5);
+    let width = camera.get_projection()[0][0].recip();
+    let height = camera.get_projection()[1][1].recip();
+    assert!((width / height - constants::WIDTH as f32 / constants::HEIGHT as f32).abs() < 1e-4);
+
+    // Zooming an orthographic projection doesn't move the camera.
+    assert_eq!(camera.get_from(), from);
+
+    // The scale never reaches zero.
+    camera.zoom(100.0);
+    assert!(camera.get_scale() > 0.0);
+}
+
+#[test]
+fn zoom_moves_perspective_camera() {
+    let mut camera = three_camera();
+    let (distance, scale) = (camera.get_from().to_vec().magnitude(), camera.get_scale());
+
+    camera.zoom(1.0);
+    assert!((camera.get_from().to_vec().magnitude() - (distance - 1.0)).abs() < 1e-4);
+    assert_eq!(camera.get_scale(), scale);
+}
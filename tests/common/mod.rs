@@ -0,0 +1,25 @@
+//! Helpers that are shared by the integration tests. Not every test uses all of them.
+#![allow(dead_code)]
+
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+/// The six regular polychora, along with their names.
+pub const POLYCHORA: [(Polychoron, &str); 6] = [
+    (Polychoron::Cell5, "5-cell"),
+    (Polychoron::Cell8, "8-cell"),
+    (Polychoron::Cell16, "16-cell"),
+    (Polychoron::Cell24, "24-cell"),
+    (Polychoron::Cell120, "120-cell"),
+    (Polychoron::Cell600, "600-cell"),
+];
+
+/// Returns the number of vertices, edges, faces, and cells of `mesh`.
+pub fn counts(mesh: &Mesh) -> [usize; 4] {
+    [
+        mesh.get_number_of_vertices(),
+        mesh.get_number_of_edges(),
+        mesh.get_number_of_faces(),
+        mesh.get_cell_faces().len(),
+    ]
+}
@@ -0,0 +1,39 @@
+extern crate four;
+
+mod common;
+
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+use common::counts;
+
+#[test]
+fn duals_of_regular_polychora() {
+    // The counts of the dual are those of the original, in reverse.
+    let polychora = [
+        (Polychoron::Cell5, "5-cell", [5, 10, 10, 5]),
+        (Polychoron::Cell8, "8-cell", [16, 32, 24, 8]),
+        (Polychoron::Cell16, "16-cell", [8, 24, 32, 16]),
+        (Polychoron::Cell24, "24-cell", [24, 96, 96, 24]),
+        (Polychoron::Cell120, "120-cell", [600, 1200, 720, 120]),
+        (Polychoron::Cell600, "600-cell", [120, 720, 1200, 600]),
+    ];
+
+    for (polychoron, name, original) in polychora.iter() {
+        let mesh = Mesh::new(*polychoron);
+        assert_eq!(counts(&mesh), *original, "{}", name);
+
+        let dual = mesh.get_dual().expect(name);
+        let mut expected = *original;
+        expected.reverse();
+        assert_eq!(counts(&dual), expected, "{}: the dual", name);
+        assert_eq!(dual.validate(), Ok(()), "{}: the dual", name);
+    }
+}
+
+#[test]
+fn duals_of_meshes_without_cells() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let tetrahedra = Mesh::from_tetrahedra(mesh.get_tetrahedra().clone(), None);
+    assert!(tetrahedra.get_dual().is_none());
+}
@@ -0,0 +1,97 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::Vector4;
+
+use four::export::CrossSection;
+use four::hyperplane::Hyperplane;
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+/// A cell-first slice of the tesseract, which is a unit cube: each of its faces is the
+/// slice of one of the 6 cells that the hyperplane passes through, and each face is
+/// split into 4 squares (8 triangles) by the tetrahedral decompositions of the cells.
+fn tesseract_slice() -> CrossSection {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    CrossSection::new(&mesh, &Hyperplane::new(Vector4::unit_w(), 0.0))
+}
+
+/// Returns the number of lines in `text` that start with `prefix`.
+fn count_lines(text: &str, prefix: &str) -> usize {
+    text.lines().filter(|line| line.starts_with(prefix)).count()
+}
+
+#[test]
+fn cross_sections_of_the_tesseract() {
+    let cross_section = tesseract_slice();
+    assert_eq!(cross_section.get_vertices().len(), 26);
+    assert_eq!(cross_section.get_triangles().len(), 48);
+
+    let mut cells = cross_section.get_cell_indices().clone();
+    cells.dedup();
+    assert_eq!(cells.len(), 6);
+}
+
+#[test]
+fn writes_obj() {
+    let cross_section = tesseract_slice();
+
+    let mut obj = Vec::new();
+    cross_section
+        .write_obj(&mut obj, Some("slice.mtl"))
+        .unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+
+    let mut lines = obj.lines();
+    assert_eq!(lines.next(), Some("# Cross-section exported by four"));
+    assert_eq!(lines.next(), Some("mtllib slice.mtl"));
+    assert_eq!(count_lines(&obj, "v "), 26);
+    assert_eq!(count_lines(&obj, "f "), 48);
+    assert_eq!(count_lines(&obj, "g "), 6);
+    assert_eq!(count_lines(&obj, "usemtl "), 6);
+
+    let mut mtl = Vec::new();
+    cross_section.write_mtl(&mut mtl).unwrap();
+    let mtl = String::from_utf8(mtl).unwrap();
+    assert_eq!(count_lines(&mtl, "newmtl "), 6);
+    assert_eq!(count_lines(&mtl, "Kd "), 6);
+}
+
+#[test]
+fn writes_obj_without_materials() {
+    let mut obj = Vec::new();
+    tesseract_slice().write_obj(&mut obj, None).unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+
+    assert_eq!(count_lines(&obj, "mtllib "), 0);
+    assert_eq!(count_lines(&obj, "usemtl "), 0);
+    assert_eq!(count_lines(&obj, "f "), 48);
+}
+
+#[test]
+fn writes_ply() {
+    let mut ply = Vec::new();
+    tesseract_slice().write_ply(&mut ply).unwrap();
+    let ply = String::from_utf8(ply).unwrap();
+
+    let (header, body) = ply.split_at(ply.find("end_header\n").unwrap());
+    assert!(header.starts_with("ply\nformat ascii 1.0\n"));
+    assert!(header.contains("\nelement vertex 26\n"));
+    assert!(header.contains("\nelement face 48\n"));
+
+    // Skip the `end_header` line itself.
+    let body = body.lines().skip(1).collect::<Vec<_>>();
+    assert_eq!(body.len(), 26 + 48);
+    assert!(body[26..].iter().all(|line| line.starts_with("3 ")));
+}
+
+#[test]
+fn writes_stl() {
+    let mut stl = Vec::new();
+    tesseract_slice().write_stl(&mut stl).unwrap();
+
+    // An 80-byte header, the number of triangles, and then 50 bytes per triangle.
+    assert_eq!(stl.len(), 80 + 4 + 48 * 50);
+    assert!(stl.starts_with(b"Cross-section exported by four"));
+    assert_eq!(&stl[80..84], &48u32.to_le_bytes());
+}
@@ -0,0 +1,109 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::Vector4;
+
+use four::hull::ConvexHull;
+use four::mesh::Mesh;
+use four::polychora::{Polychoron, Polytope};
+use four::wythoff::{CoxeterGroup, UniformPolychoron};
+
+use common::counts;
+
+/// Checks that the convex hull of the vertices of a uniform polychoron recovers the same
+/// topology as Wythoff's construction.
+fn assert_hull_matches_wythoff(group: CoxeterGroup, rings: [bool; 4]) {
+    let uniform = UniformPolychoron::new(group, rings);
+    let hull = ConvexHull::new(&uniform.get_vertices());
+
+    let expected = Mesh::new(uniform);
+    let mesh = Mesh::new(hull);
+    assert_eq!(
+        counts(&mesh),
+        counts(&expected),
+        "{:?} {:?}: the hull should have the same V, E, F, and C",
+        group,
+        rings
+    );
+    assert_eq!(mesh.validate(), Ok(()), "{:?} {:?}", group, rings);
+}
+
+/// Ring masks that cover the regular, truncated, rectified, cantellated, runcinated,
+/// bitruncated, and omnitruncated forms.
+const MASKS: [[bool; 4]; 8] = [
+    [true, false, false, false],
+    [false, true, false, false],
+    [true, true, false, false],
+    [true, false, true, false],
+    [true, false, false, true],
+    [false, true, true, false],
+    [false, false, false, true],
+    [true, true, true, true],
+];
+
+#[test]
+fn hulls_match_wythoff() {
+    let groups = [
+        CoxeterGroup::A4,
+        CoxeterGroup::B4,
+        CoxeterGroup::D4,
+        CoxeterGroup::F4,
+    ];
+    for group in groups.iter() {
+        for rings in MASKS.iter() {
+            assert_hull_matches_wythoff(*group, *rings);
+        }
+    }
+}
+
+#[test]
+fn hulls_match_wythoff_h4() {
+    // The larger H4 polychora (with thousands of vertices) are covered by the operator
+    // tests, which build them from the hull as well.
+    assert_hull_matches_wythoff(CoxeterGroup::H4, [true, false, false, false]);
+    assert_hull_matches_wythoff(CoxeterGroup::H4, [false, false, false, true]);
+    assert_hull_matches_wythoff(CoxeterGroup::H4, [false, false, true, false]);
+}
+
+#[test]
+fn hulls_of_coplanar_points_are_empty() {
+    let square = vec![
+        Vector4::new(0.0, 0.0, 0.0, 0.0),
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(1.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.5, 0.5, 0.0, 0.0),
+    ];
+    assert!(ConvexHull::new(&square).get_vertices().is_empty());
+}
+
+#[test]
+fn hulls_ignore_points_that_are_not_finite() {
+    // For example, from a malformed shape file.
+    let mut points =
+        UniformPolychoron::new(CoxeterGroup::A4, [true, false, false, false]).get_vertices();
+    points.insert(0, Vector4::new(f32::NAN, 0.0, 0.0, 0.0));
+    points.push(Vector4::new(0.0, f32::INFINITY, 0.0, 0.0));
+
+    let mesh = Mesh::new(ConvexHull::new(&points));
+    assert_eq!(counts(&mesh), [5, 10, 10, 5]);
+}
+
+#[test]
+fn hulls_ignore_points_on_the_boundary() {
+    // Duplicated vertices and the midpoints of edges lie on the boundary of the hull, so
+    // they shouldn't produce any (degenerate) facets of their own.
+    let tesseract = Mesh::new(Polychoron::Cell8);
+    let mut points = tesseract.get_vertices().clone();
+    points.extend(tesseract.get_vertices().iter().cloned());
+    for i in 0..tesseract.get_number_of_edges() {
+        let (a, b) = tesseract.get_vertices_for_edge(i as u32);
+        points.push((a + b) * 0.5);
+    }
+
+    let mesh = Mesh::new(ConvexHull::new(&points));
+    assert_eq!(counts(&mesh), [16, 32, 24, 8]);
+    assert_eq!(mesh.validate(), Ok(()));
+}
@@ -0,0 +1,122 @@
+extern crate four;
+
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+/// The golden ratio.
+const PHI: f32 = 1.618_034;
+
+/// The closed-form invariants of a regular polychoron with unit edge length.
+struct Expected {
+    hypervolume: f32,
+    boundary_volume: f32,
+    circumradius: f32,
+    inradius: f32,
+}
+
+/// Reference: `https://en.wikipedia.org/wiki/Regular_4-polytope#Properties`
+fn expected(polychoron: &Polychoron) -> Expected {
+    let sqrt_2 = 2.0f32.sqrt();
+    let sqrt_5 = 5.0f32.sqrt();
+
+    // The volume of a regular tetrahedron with unit edge length.
+    let tetrahedron = 1.0 / (6.0 * sqrt_2);
+
+    match polychoron {
+        Polychoron::Cell5 => Expected {
+            hypervolume: sqrt_5 / 96.0,
+            boundary_volume: 5.0 * tetrahedron,
+            circumradius: (2.0f32 / 5.0).sqrt(),
+            inradius: 1.0 / (2.0 * 10.0f32.sqrt()),
+        },
+        Polychoron::Cell8 => Expected {
+            hypervolume: 1.0,
+            boundary_volume: 8.0,
+            circumradius: 1.0,
+            inradius: 0.5,
+        },
+        Polychoron::Cell16 => Expected {
+            hypervolume: 1.0 / 6.0,
+            boundary_volume: 16.0 * tetrahedron,
+            circumradius: 1.0 / sqrt_2,
+            inradius: 1.0 / (2.0 * sqrt_2),
+        },
+        Polychoron::Cell24 => Expected {
+            hypervolume: 2.0,
+            boundary_volume: 24.0 * sqrt_2 / 3.0,
+            circumradius: 1.0,
+            inradius: 1.0 / sqrt_2,
+        },
+        Polychoron::Cell120 => Expected {
+            hypervolume: 15.0 / 4.0 * (105.0 + 47.0 * sqrt_5),
+            boundary_volume: 120.0 * (15.0 + 7.0 * sqrt_5) / 4.0,
+            circumradius: sqrt_2 * PHI * PHI,
+            inradius: PHI.powi(4) / 2.0,
+        },
+        Polychoron::Cell600 => Expected {
+            hypervolume: 25.0 / 4.0 * PHI.powi(3),
+            boundary_volume: 600.0 * tetrahedron,
+            circumradius: PHI,
+            inradius: PHI.powi(3) / (2.0 * sqrt_2),
+        },
+    }
+}
+
+fn assert_close(name: &str, measure: &str, actual: f32, expected: f32) {
+    let error = ((actual - expected) / expected).abs();
+    assert!(
+        error < 1.0e-3,
+        "{}: expected a {} of {}, but found {}",
+        name,
+        measure,
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn regular_polychora_match_closed_form_invariants() {
+    let polychora = vec![
+        (Polychoron::Cell5, "5-cell"),
+        (Polychoron::Cell8, "8-cell"),
+        (Polychoron::Cell16, "16-cell"),
+        (Polychoron::Cell24, "24-cell"),
+        (Polychoron::Cell120, "120-cell"),
+        (Polychoron::Cell600, "600-cell"),
+    ];
+
+    for (polychoron, name) in polychora {
+        let expected = expected(&polychoron);
+        let mesh = Mesh::new(polychoron);
+
+        // All of the edges of a regular polychoron have the same length: the invariants
+        // are scaled accordingly.
+        let edges = mesh.get_edge_statistics().unwrap();
+        assert!(
+            edges.standard_deviation < 1.0e-3 * edges.mean,
+            "{}: edges should all have the same length",
+            name
+        );
+        let a = edges.mean;
+
+        assert_close(
+            name,
+            "hypervolume",
+            mesh.get_hypervolume(),
+            expected.hypervolume * a.powi(4),
+        );
+        assert_close(
+            name,
+            "boundary volume",
+            mesh.get_boundary_volume(),
+            expected.boundary_volume * a.powi(3),
+        );
+        assert_close(
+            name,
+            "circumradius",
+            mesh.get_circumradius(),
+            expected.circumradius * a,
+        );
+        assert_close(name, "inradius", mesh.get_inradius(), expected.inradius * a);
+    }
+}
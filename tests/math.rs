@@ -0,0 +1,33 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::Vector4;
+
+use four::math;
+
+#[test]
+fn sorts_the_vertices_of_a_polygon() {
+    // The corners of a square in the `xw`-plane, out of order.
+    let points = [
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(-1.0, 0.0, 0.0, -1.0),
+        Vector4::new(1.0, 0.0, 0.0, -1.0),
+        Vector4::new(-1.0, 0.0, 0.0, 1.0),
+    ];
+    let sorted = math::sort_indices_on_plane(&points);
+    assert_eq!(sorted.len(), 4);
+
+    // Consecutive corners share a coordinate, while opposite corners don't.
+    for k in 0..4 {
+        let (a, b) = (points[sorted[k]], points[sorted[(k + 1) % 4]]);
+        assert!(a.x == b.x || a.w == b.w);
+    }
+}
+
+#[test]
+fn sorting_degenerate_polygons_does_not_panic() {
+    let point = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let mut sorted = math::sort_indices_on_plane(&[point, point, point]);
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2]);
+}
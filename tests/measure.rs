@@ -0,0 +1,61 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::Vector4;
+
+use four::measure::Sweep;
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+#[test]
+fn tesseract_volume_profile_is_constant() {
+    // The tesseract has unit edge length, so every cell-first slice (including the two at
+    // `w = ±0.5`, which contain an entire cell) is a unit cube.
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let sweep = Sweep::new(&mesh, Vector4::unit_w(), -0.5, 0.5, 21);
+
+    for (displacement, measurements) in sweep.get_samples().iter() {
+        assert!(
+            (measurements.volume - 1.0).abs() < 1.0e-4,
+            "expected a volume of 1 at {}, but found {}",
+            displacement,
+            measurements.volume
+        );
+        assert!((measurements.surface_area - 6.0).abs() < 1.0e-4);
+    }
+}
+
+#[test]
+fn tesseract_volume_profile_is_empty_outside() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    for displacement in [-0.75, 0.75].iter() {
+        let sweep = Sweep::new(&mesh, Vector4::unit_w(), *displacement, *displacement, 1);
+        assert_eq!(sweep.get_samples()[0].1.volume, 0.0);
+    }
+}
+
+#[test]
+fn sweep_is_written_as_csv() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let sweep = Sweep::new(&mesh, Vector4::unit_w(), -0.25, 0.25, 5);
+
+    let mut csv = Vec::new();
+    sweep.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines[0],
+        "displacement,distance,volume,surface_area,centroid_x,centroid_y,centroid_z,centroid_w,cells"
+    );
+    assert_eq!(lines.len(), 1 + 5);
+
+    // Each of these slices passes through the 6 cells that aren't parallel to it.
+    for (line, (displacement, _)) in lines[1..].iter().zip(sweep.get_samples().iter()) {
+        let fields = line.split(',').collect::<Vec<_>>();
+        assert_eq!(fields.len(), 9);
+        assert_eq!(fields[0].parse::<f32>().unwrap(), *displacement);
+        assert!((fields[2].parse::<f32>().unwrap() - 1.0).abs() < 1.0e-4);
+        assert_eq!(fields[8], "6");
+    }
+}
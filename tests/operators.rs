@@ -0,0 +1,134 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::InnerSpace;
+
+use four::mesh::Mesh;
+use four::operators;
+use four::polychora::Polychoron;
+
+use common::counts;
+
+/// Checks that applying `operator` to `polychoron` gives a mesh with the `expected`
+/// numbers of vertices, edges, faces, and cells. Note that the operators only return
+/// meshes that are valid (see `Mesh::validate(...)`).
+fn assert_counts(
+    operator: fn(&Mesh) -> Option<Mesh>,
+    polychoron: Polychoron,
+    name: &str,
+    expected: [usize; 4],
+) {
+    let mesh = operator(&Mesh::new(polychoron)).expect(name);
+    assert_eq!(counts(&mesh), expected, "{}", name);
+}
+
+#[test]
+fn rectified_polychora() {
+    let rectify = operators::rectify;
+    assert_counts(rectify, Polychoron::Cell5, "5-cell", [10, 30, 30, 10]);
+    assert_counts(rectify, Polychoron::Cell8, "8-cell", [32, 96, 88, 24]);
+    assert_counts(rectify, Polychoron::Cell16, "16-cell", [24, 96, 96, 24]);
+    assert_counts(rectify, Polychoron::Cell24, "24-cell", [96, 288, 240, 48]);
+    assert_counts(
+        rectify,
+        Polychoron::Cell120,
+        "120-cell",
+        [1200, 3600, 3120, 720],
+    );
+    assert_counts(
+        rectify,
+        Polychoron::Cell600,
+        "600-cell",
+        [720, 3600, 3600, 720],
+    );
+}
+
+#[test]
+fn truncated_polychora() {
+    let truncate = operators::truncate;
+    assert_counts(truncate, Polychoron::Cell5, "5-cell", [20, 40, 30, 10]);
+    assert_counts(truncate, Polychoron::Cell8, "8-cell", [64, 128, 88, 24]);
+    assert_counts(truncate, Polychoron::Cell16, "16-cell", [48, 120, 96, 24]);
+    assert_counts(truncate, Polychoron::Cell24, "24-cell", [192, 384, 240, 48]);
+    assert_counts(
+        truncate,
+        Polychoron::Cell120,
+        "120-cell",
+        [2400, 4800, 3120, 720],
+    );
+    assert_counts(
+        truncate,
+        Polychoron::Cell600,
+        "600-cell",
+        [1440, 4320, 3600, 720],
+    );
+}
+
+#[test]
+fn bitruncated_polychora() {
+    let bitruncate = operators::bitruncate;
+    assert_counts(bitruncate, Polychoron::Cell5, "5-cell", [30, 60, 40, 10]);
+    assert_counts(bitruncate, Polychoron::Cell8, "8-cell", [96, 192, 120, 24]);
+    assert_counts(
+        bitruncate,
+        Polychoron::Cell16,
+        "16-cell",
+        [96, 192, 120, 24],
+    );
+    assert_counts(
+        bitruncate,
+        Polychoron::Cell24,
+        "24-cell",
+        [288, 576, 336, 48],
+    );
+    assert_counts(
+        bitruncate,
+        Polychoron::Cell120,
+        "120-cell",
+        [3600, 7200, 4320, 720],
+    );
+    assert_counts(
+        bitruncate,
+        Polychoron::Cell600,
+        "600-cell",
+        [3600, 7200, 4320, 720],
+    );
+}
+
+#[test]
+fn snub_polychora() {
+    assert_counts(
+        operators::snub,
+        Polychoron::Cell24,
+        "24-cell",
+        [96, 432, 480, 144],
+    );
+
+    // The truncations of the other regular polychora have triangular faces, so they can't
+    // be alternated.
+    let others = [
+        Polychoron::Cell5,
+        Polychoron::Cell8,
+        Polychoron::Cell16,
+        Polychoron::Cell120,
+        Polychoron::Cell600,
+    ];
+    for polychoron in others.iter() {
+        assert!(operators::snub(&Mesh::new(*polychoron)).is_none());
+    }
+}
+
+#[test]
+fn compound_of_tesseract_and_dual() {
+    let mesh = operators::compound_with_dual(&Mesh::new(Polychoron::Cell8)).unwrap();
+
+    // The 16 vertices of the tesseract and the 8 vertices of the 16-cell.
+    assert_eq!(counts(&mesh), [16 + 8, 32 + 24, 24 + 32, 8 + 16]);
+
+    // The dual is scaled to share the tesseract's circumradius.
+    for vertex in mesh.get_vertices() {
+        assert!((vertex.magnitude() - 1.0).abs() < 1e-5);
+    }
+}
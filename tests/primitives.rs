@@ -0,0 +1,100 @@
+extern crate cgmath;
+extern crate four;
+
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use cgmath::Vector4;
+
+use four::mesh::Mesh;
+use four::primitives;
+use four::tetrahedron::Tetrahedron;
+
+/// Tessellates some shape with the given resolution.
+type Tessellation = fn(u32) -> Vec<Tetrahedron>;
+
+/// Returns a point on a grid that is fine enough to weld together the vertices that a
+/// tessellation computes more than once (i.e. along its seams).
+fn quantize(vertex: &Vector4<f32>) -> [i32; 4] {
+    let round = |value: f32| (value * 1.0e4).round() as i32;
+    [
+        round(vertex.x),
+        round(vertex.y),
+        round(vertex.z),
+        round(vertex.w),
+    ]
+}
+
+/// Checks that every triangle of the given tetrahedra is shared by exactly two of them,
+/// i.e. that they form a closed 3-manifold without any gaps or overlaps.
+fn assert_closed(tetrahedra: &[Tetrahedron], name: &str) {
+    let mut triangles = HashMap::new();
+    for tetrahedron in tetrahedra.iter() {
+        let vertices = tetrahedron.get_vertices();
+
+        for skip in 0..vertices.len() {
+            let mut triangle = (0..vertices.len())
+                .filter(|i| *i != skip)
+                .map(|i| quantize(&vertices[i]))
+                .collect::<Vec<_>>();
+            triangle.sort();
+            *triangles.entry(triangle).or_insert(0) += 1;
+        }
+    }
+
+    assert!(!triangles.is_empty(), "{}", name);
+    for count in triangles.values() {
+        assert_eq!(*count, 2, "{}", name);
+    }
+}
+
+#[test]
+fn tessellations_are_closed() {
+    let resolution = 16;
+    assert_closed(&primitives::hypersphere(1.0, resolution), "hypersphere");
+    assert_closed(&primitives::spherinder(1.0, 1.0, resolution), "spherinder");
+    assert_closed(&primitives::cubinder(0.5, 1.0, resolution), "cubinder");
+    assert_closed(
+        &primitives::duocylinder(1.0, 0.5, resolution),
+        "duocylinder",
+    );
+    assert_closed(
+        &primitives::clifford_torus(0.1, resolution),
+        "Clifford torus",
+    );
+}
+
+#[test]
+fn hypervolumes_converge() {
+    let primitives: [(Tessellation, &str, f32); 4] = [
+        (
+            |n| primitives::hypersphere(1.0, n),
+            "hypersphere",
+            PI * PI / 2.0,
+        ),
+        (
+            |n| primitives::spherinder(1.0, 1.0, n),
+            "spherinder",
+            4.0 / 3.0 * PI,
+        ),
+        (|n| primitives::cubinder(0.5, 1.0, n), "cubinder", PI),
+        (
+            |n| primitives::duocylinder(1.0, 0.5, n),
+            "duocylinder",
+            PI * PI / 4.0,
+        ),
+    ];
+
+    for (tessellate, name, expected) in primitives.iter() {
+        // The tessellations are inscribed in the (convex) shapes, so they always fall
+        // short of the analytic hypervolume, but less so as the resolution increases.
+        let mut previous_error = f32::MAX;
+        for resolution in [8, 16, 32].iter() {
+            let mesh = Mesh::from_tetrahedra(tessellate(*resolution), None);
+            let error = (expected - mesh.get_hypervolume()) / expected;
+            assert!(error > 0.0 && error < previous_error, "{}", name);
+            previous_error = error;
+        }
+        assert!(previous_error < 0.05, "{}", name);
+    }
+}
@@ -0,0 +1,91 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::Vector3;
+
+use four::mesh::Mesh;
+use four::prism::{Duoprism, Polyhedron, Prism};
+
+use common::counts;
+
+/// Returns the number of vertices, edges, and faces of `polyhedron`.
+fn polyhedron_counts(polyhedron: &Polyhedron) -> [usize; 3] {
+    [
+        polyhedron.get_vertices().len(),
+        polyhedron.get_edges().len() / 2,
+        polyhedron.get_faces().len(),
+    ]
+}
+
+#[test]
+fn polyhedra_from_vertices() {
+    assert_eq!(polyhedron_counts(&Polyhedron::tetrahedron()), [4, 6, 4]);
+    assert_eq!(polyhedron_counts(&Polyhedron::cube()), [8, 12, 6]);
+    assert_eq!(polyhedron_counts(&Polyhedron::octahedron()), [6, 12, 8]);
+    assert_eq!(polyhedron_counts(&Polyhedron::dodecahedron()), [20, 30, 12]);
+    assert_eq!(polyhedron_counts(&Polyhedron::icosahedron()), [12, 30, 20]);
+
+    // A triangular prism, which has faces with different numbers of sides.
+    let triangular_prism = Polyhedron::from_vertices(vec![
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(-0.5, 0.866, -1.0),
+        Vector3::new(-0.5, -0.866, -1.0),
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(-0.5, 0.866, 1.0),
+        Vector3::new(-0.5, -0.866, 1.0),
+    ]);
+    assert_eq!(polyhedron_counts(&triangular_prism), [6, 9, 5]);
+}
+
+#[test]
+fn prisms_over_polyhedra() {
+    let expected = [
+        (
+            Polyhedron::tetrahedron(),
+            "tetrahedral prism",
+            [8, 16, 14, 6],
+        ),
+        // The cubic prism is the tesseract.
+        (Polyhedron::cube(), "cubic prism", [16, 32, 24, 8]),
+        (
+            Polyhedron::octahedron(),
+            "octahedral prism",
+            [12, 30, 28, 10],
+        ),
+        (
+            Polyhedron::dodecahedron(),
+            "dodecahedral prism",
+            [40, 80, 54, 14],
+        ),
+        (
+            Polyhedron::icosahedron(),
+            "icosahedral prism",
+            [24, 72, 70, 22],
+        ),
+    ];
+
+    for (base, name, expected) in expected.iter() {
+        let mesh = Mesh::new(Prism::new(base, base.get_edge_length()));
+        assert_eq!(counts(&mesh), *expected, "{}", name);
+        assert_eq!(mesh.validate(), Ok(()), "{}", name);
+    }
+}
+
+#[test]
+fn duoprisms() {
+    let expected = [
+        (3, 3, [9, 18, 15, 6]),
+        (3, 4, [12, 24, 19, 7]),
+        // The 4,4-duoprism is also the tesseract.
+        (4, 4, [16, 32, 24, 8]),
+        (5, 7, [35, 70, 47, 12]),
+    ];
+
+    for (p, q, expected) in expected.iter() {
+        let mesh = Mesh::new(Duoprism::new(*p, *q));
+        assert_eq!(counts(&mesh), *expected, "{},{}-duoprism", p, q);
+        assert_eq!(mesh.validate(), Ok(()), "{},{}-duoprism", p, q);
+    }
+}
@@ -0,0 +1,88 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::InnerSpace;
+
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+use four::primitives;
+use four::schlegel::SchlegelDiagram;
+
+use common::POLYCHORA;
+
+#[test]
+fn every_cell_is_projected() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let mesh = Mesh::new(*polychoron);
+        let complex = mesh.get_boundary_complex().expect(name);
+        let cells = SchlegelDiagram::new(&mesh, 0).expect(name).project(1.0);
+
+        assert_eq!(cells.len(), mesh.get_cell_faces().len(), "{}", name);
+        for (i, cell) in cells.iter().enumerate() {
+            assert_eq!(
+                cell.len(),
+                complex.get_vertices_of_cell(i as u32).len(),
+                "{}",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn projection_cell_is_fixed() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let mesh = Mesh::new(*polychoron);
+        let complex = mesh.get_boundary_complex().expect(name);
+        let circumradius = mesh.get_circumradius();
+
+        for cell in [0, complex.get_number_of_cells() as u32 - 1].iter() {
+            let diagram = SchlegelDiagram::new(&mesh, *cell).expect(name);
+            let projected = &diagram.project(0.5)[*cell as usize];
+            let original = complex
+                .get_vertices_of_cell(*cell)
+                .iter()
+                .map(|vertex| complex.get_vertex(*vertex))
+                .collect::<Vec<_>>();
+
+            // The vertices of the projection cell already lie in its hyperplane, so they
+            // stay where they are (and aren't shrunk): only the scale of the diagram, which
+            // makes its outer hull as large as the polychoron, is applied.
+            let centroid = complex.get_cell_centroid(*cell);
+            let scale = circumradius / (original[0] - centroid).magnitude();
+            for (a, projected_a) in original.iter().zip(projected.iter()) {
+                assert!(projected_a.w.abs() < 1e-4, "{}", name);
+                for (b, projected_b) in original.iter().zip(projected.iter()) {
+                    let distance = (projected_a.truncate() - projected_b.truncate()).magnitude();
+                    assert!(
+                        (distance - (a - b).magnitude() * scale).abs() < 1e-3,
+                        "{}",
+                        name
+                    );
+                }
+            }
+
+            // All of the other cells are nested inside of the projection cell.
+            for vertices in diagram.project(1.0).iter() {
+                for vertex in vertices.iter() {
+                    assert!(
+                        vertex.truncate().magnitude() < circumradius + 1e-3,
+                        "{}",
+                        name
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn meshes_without_cells_are_rejected() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    assert!(SchlegelDiagram::new(&mesh, 8).is_none());
+
+    let mesh = Mesh::from_tetrahedra(primitives::hypersphere(1.0, 8), None);
+    assert!(SchlegelDiagram::new(&mesh, 0).is_none());
+}
@@ -0,0 +1,139 @@
+extern crate four;
+
+use std::path::Path;
+
+use four::polychora::Polytope;
+use four::shape_file::{ShapeFile, ShapeFileError};
+
+/// A 5-cell, in the shape file format.
+const CELL_5: &str = "5
+0.25 0.322749 0.456435 0.790569
+0.25 0.322749 0.456435 -0.790569
+0.25 0.322749 -0.912871 0.0
+0.25 -0.968246 0.0 0.0
+-1.0 0.0 0.0 0.0
+
+10
+0 1
+0 2
+0 3
+0 4
+1 2
+1 3
+1 4
+2 3
+2 4
+3 4
+
+10
+0 1 2
+0 1 3
+0 1 4
+0 2 3
+0 2 4
+0 3 4
+1 2 3
+1 2 4
+1 3 4
+2 3 4
+";
+
+/// Parses `contents`, which should be malformed, and returns the error message.
+fn parse_error(contents: &str) -> String {
+    match ShapeFile::parse(contents) {
+        Ok(_) => panic!("expected a parse error"),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn parses_a_valid_shape_file() {
+    let shape = ShapeFile::parse(CELL_5).unwrap();
+
+    assert_eq!(shape.get_vertices().len(), 5);
+    assert_eq!(shape.get_edges().len(), 20);
+    assert_eq!(shape.get_faces().len(), 10);
+    assert_eq!(shape.get_h_representation().len(), 5);
+}
+
+#[test]
+fn reports_malformed_numbers() {
+    assert_eq!(
+        parse_error(&CELL_5.replace("0.25 -0.968246", "0.25 x")),
+        "5:6: expected a coordinate, found `x`"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n10\n0 1\n", "\nten\n0 1\n")),
+        "8:1: expected the number of edges, found `ten`"
+    );
+}
+
+#[test]
+fn reports_the_wrong_number_of_values() {
+    assert_eq!(
+        parse_error(&CELL_5.replace("-1.0 0.0 0.0 0.0", "-1.0 0.0 0.0")),
+        "6:1: expected 4 coordinates per vertex, found 3"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n2 4\n", "\n2 4 1\n")),
+        "17:1: expected 2 vertex indices per edge, found 3"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n2 3 4\n", "\n2 3\n")),
+        "30:1: expected at least 3 vertex indices per face, found 2"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n10\n0 1\n", "\n10 11\n0 1\n")),
+        "8:4: expected a single integer (the number of edges)"
+    );
+}
+
+#[test]
+fn reports_out_of_range_indices() {
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n1 3 4\n", "\n1 3 5\n")),
+        "29:5: vertex index 5 is out of range (there are 5 vertices)"
+    );
+}
+
+#[test]
+fn reports_oversized_counts() {
+    let huge = usize::MAX.to_string();
+    assert_eq!(
+        parse_error(&CELL_5.replacen("5\n", &format!("{}\n", huge), 1)),
+        "8:1: expected 4 coordinates per vertex, found 1"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replacen("\n10\n", &format!("\n{}\n", huge), 1)),
+        "20:1: expected 2 vertex indices per edge, found 1"
+    );
+    assert_eq!(
+        parse_error(&CELL_5.replacen("\n\n10\n0 1 2", &format!("\n\n{}\n0 1 2", huge), 1)),
+        "31:1: unexpected end of file, expected a face"
+    );
+}
+
+#[test]
+fn reports_missing_and_trailing_content() {
+    assert_eq!(
+        parse_error(&CELL_5.replace("\n2 3 4\n", "\n")),
+        "30:1: unexpected end of file, expected a face"
+    );
+    assert_eq!(
+        parse_error(&format!("{}\n  42\n", CELL_5)),
+        "32:3: unexpected trailing content"
+    );
+    assert_eq!(
+        parse_error(""),
+        "1:1: unexpected end of file, expected the number of vertices"
+    );
+}
+
+#[test]
+fn reports_missing_files() {
+    match ShapeFile::load(Path::new("shapes/does_not_exist.txt")) {
+        Err(ShapeFileError::Io(_)) => (),
+        Err(error) => panic!("expected an IO error, found: {}", error),
+        Ok(_) => panic!("expected an IO error"),
+    }
+}
@@ -0,0 +1,36 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::{InnerSpace, Vector4};
+
+use four::hyperplane::Hyperplane;
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+#[test]
+fn cell_first_slice_of_tesseract_is_six_squares() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let slices = mesh.slice_cells(&Hyperplane::new(Vector4::unit_w(), 0.0));
+
+    // The two cells that are parallel to the hyperplane are missed, and each of the
+    // other six cells is cut through the middle.
+    assert_eq!(slices.len(), 6);
+    for slice in slices.iter() {
+        assert_eq!(slice.vertices.len(), 4);
+
+        // Consecutive vertices should be joined by unit-length edges.
+        for (i, vertex) in slice.vertices.iter().enumerate() {
+            let next = slice.vertices[(i + 1) % slice.vertices.len()];
+            assert!(((next - vertex).magnitude() - 1.0).abs() < 1e-5);
+            assert!(vertex.w.abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn slice_that_misses_tesseract_is_empty() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let slices = mesh.slice_cells(&Hyperplane::new(Vector4::unit_w(), -1.0));
+
+    assert!(slices.is_empty());
+}
@@ -0,0 +1,51 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::{InnerSpace, Vector4};
+
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+use four::stereographic::StereographicProjection;
+
+#[test]
+fn equator_is_fixed() {
+    // The basis of the image for a pole along the `w`-axis is the `x`, `y`, and `z`-axes.
+    let projection = StereographicProjection::new(&Vector4::unit_w(), 1);
+    let points = [
+        Vector4::unit_x(),
+        -Vector4::unit_y(),
+        Vector4::new(0.6, 0.0, 0.8, 0.0),
+        Vector4::new(1.0, -1.0, 1.0, 0.0).normalize(),
+    ];
+
+    for point in points.iter() {
+        let projected = projection.project_point(point).unwrap();
+        assert!((projected - point).magnitude() < 1e-6);
+    }
+}
+
+#[test]
+fn points_near_the_pole_are_skipped() {
+    let projection = StereographicProjection::new(&Vector4::unit_w(), 1);
+
+    assert!(projection.project_point(&Vector4::unit_w()).is_none());
+    assert!(projection.project_point(&-Vector4::unit_w()).is_some());
+}
+
+#[test]
+fn every_edge_is_projected() {
+    let mesh = Mesh::new(Polychoron::Cell8);
+    let segments = 4;
+
+    // None of the edges of the tesseract come close to the pole, so each of them is
+    // projected as `segments` line segments (i.e. pairs of vertices).
+    let projection = StereographicProjection::new(&Vector4::unit_w(), segments);
+    let lines = projection.project_edges(&mesh);
+    assert_eq!(lines.len(), mesh.get_number_of_edges() * segments * 2);
+
+    // With the pole at one of the vertices, the segments that touch the 4 edges around
+    // it are skipped.
+    let projection = StereographicProjection::new(&Vector4::new(1.0, 1.0, 1.0, 1.0), segments);
+    let lines = projection.project_edges(&mesh);
+    assert_eq!(lines.len(), (mesh.get_number_of_edges() * segments - 4) * 2);
+}
@@ -0,0 +1,126 @@
+extern crate cgmath;
+extern crate four;
+
+use cgmath::{InnerSpace, Vector4, Zero};
+
+use four::hyperplane::Hyperplane;
+use four::tetrahedron::{Slice, Tetrahedron};
+
+/// The hyperplane `w = 0`.
+fn hyperplane() -> Hyperplane {
+    Hyperplane::new(Vector4::unit_w(), 0.0)
+}
+
+fn tetrahedron(vertices: [Vector4<f32>; 4]) -> Tetrahedron {
+    Tetrahedron::new(vertices, 0, Vector4::zero())
+}
+
+fn assert_points_eq(actual: &[Vector4<f32>], expected: &[Vector4<f32>]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, b) in actual.iter().zip(expected.iter()) {
+        assert!(
+            (a - b).magnitude() < 1.0e-6,
+            "expected {:?}, found {:?}",
+            b,
+            a
+        );
+    }
+}
+
+#[test]
+fn tetrahedra_on_one_side_are_not_sliced() {
+    let above = tetrahedron([
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 1.0, 0.0, 2.0),
+        Vector4::new(0.0, 0.0, 1.0, 3.0),
+    ]);
+
+    assert_eq!(above.slice(&hyperplane()), Slice::Empty);
+    assert!(above.slice(&hyperplane()).get_triangles().is_empty());
+}
+
+#[test]
+fn cutting_off_one_vertex_produces_a_triangle() {
+    let tetrahedron = tetrahedron([
+        Vector4::new(0.0, 0.0, 0.0, -1.0),
+        Vector4::new(2.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 2.0, 0.0, 1.0),
+        Vector4::new(0.0, 0.0, 2.0, 3.0),
+    ]);
+
+    // The intersections are found along the edges in order: (0, 1), (0, 2), and (0, 3).
+    match tetrahedron.slice(&hyperplane()) {
+        Slice::Triangle(points) => assert_points_eq(
+            &points,
+            &[
+                Vector4::new(1.0, 0.0, 0.0, 0.0),
+                Vector4::new(0.0, 1.0, 0.0, 0.0),
+                Vector4::new(0.0, 0.0, 0.5, 0.0),
+            ],
+        ),
+        slice => panic!("expected a triangle, found {:?}", slice),
+    }
+}
+
+#[test]
+fn separating_two_pairs_of_vertices_produces_a_sorted_quad() {
+    let (a, b, c, d) = (
+        Vector4::new(0.0, 0.0, 0.0, -1.0),
+        Vector4::new(1.0, 0.0, 0.0, -1.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 0.0, 1.0, 1.0),
+    );
+    let tetrahedron = tetrahedron([a, b, c, d]);
+
+    // The midpoints of the edges `ac`, `ad`, `bd`, and `bc`, in order around the quad:
+    // each consecutive pair lies on a common face of the tetrahedron.
+    let around = [(a + c) * 0.5, (a + d) * 0.5, (b + d) * 0.5, (b + c) * 0.5];
+
+    let points = match tetrahedron.slice(&hyperplane()) {
+        Slice::Quad(points) => points,
+        slice => panic!("expected a quad, found {:?}", slice),
+    };
+
+    let position = |point: &Vector4<f32>| {
+        around
+            .iter()
+            .position(|expected| (expected - point).magnitude() < 1.0e-6)
+            .expect("unexpected point of intersection")
+    };
+    for i in 0..4 {
+        let (current, next) = (position(&points[i]), position(&points[(i + 1) % 4]));
+        assert!(
+            (current + 1) % 4 == next || (next + 1) % 4 == current,
+            "the points of the quad are out of order: {:?}",
+            points
+        );
+    }
+
+    // The two triangles of the quad cover the whole `0.5 x sqrt(0.5)` rectangle.
+    let area = tetrahedron
+        .slice(&hyperplane())
+        .get_triangles()
+        .iter()
+        .map(|[p, q, r]| {
+            let (u, v) = (q - p, r - p);
+            (u.magnitude2() * v.magnitude2() - u.dot(v).powi(2)).sqrt() * 0.5
+        })
+        .sum::<f32>();
+    assert!((area - 0.5 * 0.5f32.sqrt()).abs() < 1.0e-6);
+}
+
+#[test]
+fn degenerate_quads_do_not_panic() {
+    // Both pairs of vertices are coincident, so all of the intersections coincide, which
+    // makes the angles that the quad is sorted by NaN.
+    let (a, c) = (
+        Vector4::new(0.0, 0.0, 0.0, -1.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+    );
+    let slice = tetrahedron([a, a, c, c]).slice(&hyperplane());
+
+    for point in slice.get_vertices().iter() {
+        assert!((point - (a + c) * 0.5).magnitude() < 1.0e-6);
+    }
+}
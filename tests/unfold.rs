@@ -0,0 +1,84 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::InnerSpace;
+
+use four::mesh::Mesh;
+
+use common::POLYCHORA;
+
+#[test]
+fn unfolded_nets_are_flat() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let net = Mesh::new(*polychoron).get_net().expect(name);
+
+        let w = net
+            .unfold(1.0)
+            .iter()
+            .flat_map(|cell| cell.iter())
+            .map(|vertex| vertex.w.abs())
+            .fold(0.0, f32::max);
+        assert!(w < 1.0e-6, "{}: a vertex is {} away from the net", name, w);
+    }
+}
+
+#[test]
+fn cells_are_rigid_while_unfolding() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let net = Mesh::new(*polychoron).get_net().expect(name);
+
+        let folded = net.unfold(0.0);
+        let unfolded = net.unfold(0.5);
+        for (i, (before, after)) in folded.iter().zip(unfolded.iter()).enumerate() {
+            for [a, b] in net.get_cell_edges(i as u32).iter() {
+                let (a, b) = (*a as usize, *b as usize);
+                let length = (before[a] - before[b]).magnitude();
+                assert!(
+                    ((after[a] - after[b]).magnitude() - length).abs() < 1.0e-5,
+                    "{}: cell {} changes shape",
+                    name,
+                    i
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn cells_stay_attached_to_their_parents() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let mesh = Mesh::new(*polychoron);
+        let complex = mesh.get_boundary_complex().expect(name);
+        let net = mesh.get_net().expect(name);
+
+        let unfolded = net.unfold(1.0);
+        for i in 0..net.get_number_of_cells() as u32 {
+            let (parent, hinge) = match (net.get_parent(i), net.get_hinge(i)) {
+                (Some(parent), Some(hinge)) => (parent, hinge),
+                _ => continue,
+            };
+
+            // Find where each vertex of the hinge ends up, as part of either cell.
+            let position = |cell: u32, vertex: u32| {
+                let index = complex
+                    .get_vertices_of_cell(cell)
+                    .iter()
+                    .position(|other| *other == vertex)
+                    .unwrap();
+                unfolded[cell as usize][index]
+            };
+            for vertex in complex.get_face(hinge).iter() {
+                let gap = (position(i, *vertex) - position(parent, *vertex)).magnitude();
+                assert!(
+                    gap < 1.0e-5,
+                    "{}: cell {} is {} away from its parent",
+                    name,
+                    i,
+                    gap
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use cgmath::Vector4;
+
+use four::hyperplane::Hyperplane;
+use four::mesh::Mesh;
+use four::polychora::{Definition, Polychoron, Polytope};
+use four::topology::TopologyError;
+
+use common::POLYCHORA;
+
+/// A 5-cell that has been broken in some way.
+struct Broken {
+    faces: Vec<Vec<u32>>,
+    h_representation: Vec<Hyperplane>,
+}
+
+impl Broken {
+    fn new() -> Broken {
+        Broken {
+            faces: Polychoron::Cell5.get_faces(),
+            h_representation: Polychoron::Cell5.get_h_representation(),
+        }
+    }
+}
+
+impl Polytope for Broken {
+    fn get_definition(&self) -> Definition {
+        Polychoron::Cell5.get_definition()
+    }
+
+    fn get_vertices(&self) -> Vec<Vector4<f32>> {
+        Polychoron::Cell5.get_vertices()
+    }
+
+    fn get_edges(&self) -> Vec<u32> {
+        Polychoron::Cell5.get_edges()
+    }
+
+    fn get_faces(&self) -> Vec<Vec<u32>> {
+        self.faces.clone()
+    }
+
+    fn get_h_representation(&self) -> Vec<Hyperplane> {
+        self.h_representation.clone()
+    }
+}
+
+#[test]
+fn regular_polychora_are_valid() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        assert_eq!(Mesh::new(*polychoron).validate(), Ok(()), "{}", name);
+    }
+}
+
+#[test]
+fn meshes_built_from_tetrahedra_are_missing_topology() {
+    let tetrahedra = Mesh::new(Polychoron::Cell8).get_tetrahedra().clone();
+    let mesh = Mesh::from_tetrahedra(tetrahedra, None);
+    assert_eq!(mesh.validate(), Err(vec![TopologyError::MissingTopology]));
+}
+
+#[test]
+fn duplicated_cells_are_reported() {
+    // Each of the faces of the first cell now belongs to that cell twice over, along with
+    // its neighbor.
+    let mut broken = Broken::new();
+    let first = broken.h_representation[0];
+    broken.h_representation.push(first);
+
+    let errors = Mesh::new(broken).validate().unwrap_err();
+    assert!(errors.contains(&TopologyError::EulerCharacteristic {
+        vertices: 5,
+        edges: 10,
+        faces: 10,
+        cells: 6,
+    }));
+
+    let unshared = errors
+        .iter()
+        .filter(|error| matches!(error, TopologyError::FaceNotShared { cells: 3, .. }))
+        .count();
+    assert_eq!(unshared, 4);
+    assert_eq!(errors.len(), 5);
+}
+
+#[test]
+fn missing_faces_are_reported() {
+    // The two cells that shared the removed face are left open, with 3 faces each.
+    let mut broken = Broken::new();
+    broken.faces.pop();
+
+    let errors = Mesh::new(broken).validate().unwrap_err();
+    assert!(errors.contains(&TopologyError::EulerCharacteristic {
+        vertices: 5,
+        edges: 10,
+        faces: 9,
+        cells: 5,
+    }));
+
+    let degenerate = errors
+        .iter()
+        .filter(|error| matches!(error, TopologyError::DegenerateCell { faces: 3, .. }))
+        .count();
+    assert_eq!(degenerate, 2);
+}
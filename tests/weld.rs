@@ -0,0 +1,88 @@
+extern crate cgmath;
+extern crate four;
+
+mod common;
+
+use std::collections::HashMap;
+
+use cgmath::Vector4;
+
+use four::hyperplane::Hyperplane;
+use four::measure::SliceMeasurements;
+use four::mesh::Mesh;
+use four::polychora::Polychoron;
+
+use common::POLYCHORA;
+
+/// Slices `mesh` with the hyperplane `w = -displacement`, checks that the cross-section
+/// is exactly one closed polyhedron, and returns its volume.
+fn slice_volume(mesh: &Mesh, displacement: f32, name: &str) -> f32 {
+    let hyperplane = Hyperplane::new(Vector4::unit_w(), displacement);
+    let polyhedra = mesh.slice_polyhedra(&hyperplane);
+    assert_eq!(
+        polyhedra.len(),
+        1,
+        "{} at {}: expected exactly one polyhedron",
+        name,
+        displacement
+    );
+
+    // Every edge of a closed polyhedron is shared by exactly two of its faces.
+    let mut edges: HashMap<(u32, u32), usize> = HashMap::new();
+    for face in polyhedra[0].get_faces().iter() {
+        for k in 0..face.len() {
+            let (a, b) = (face[k], face[(k + 1) % face.len()]);
+            *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    assert!(
+        edges.values().all(|count| *count == 2),
+        "{} at {}: the polyhedron isn't closed",
+        name,
+        displacement
+    );
+
+    SliceMeasurements::new(&polyhedra, &hyperplane).volume
+}
+
+#[test]
+fn slices_through_vertices_are_closed() {
+    for (polychoron, name) in POLYCHORA.iter() {
+        let mesh = Mesh::new(*polychoron);
+
+        // Each of the regular polychora has vertices with `w = 0`, apart from the 8-cell.
+        let volume = slice_volume(&mesh, 0.0, name);
+
+        // The volume should be (nearly) the same as that of a nearby slice, which doesn't
+        // pass through any vertices.
+        let nearby = slice_volume(&mesh, 1.0e-3, name);
+        assert!(
+            (volume - nearby).abs() < 1.0e-2 * nearby,
+            "{}: expected a volume of about {}, but found {}",
+            name,
+            nearby,
+            volume
+        );
+    }
+}
+
+#[test]
+fn slices_through_vertices_have_the_right_volume() {
+    // An octahedron, with vertices at the unit vectors.
+    let volume = slice_volume(&Mesh::new(Polychoron::Cell16), 0.0, "16-cell");
+    assert!((volume - 4.0 / 3.0).abs() < 1.0e-4);
+
+    // A rhombic dodecahedron, which is a unit cube with a pyramid on each of its faces.
+    let volume = slice_volume(&Mesh::new(Polychoron::Cell24), 0.0, "24-cell");
+    assert!((volume - 2.0).abs() < 1.0e-4);
+}
+
+#[test]
+fn slices_through_cells_are_closed() {
+    // The hyperplane contains one of the cubic cells of the 8-cell, so the cross-section
+    // is that cube: its faces come from the neighboring cells.
+    for displacement in [-0.5, 0.5].iter() {
+        let volume = slice_volume(&Mesh::new(Polychoron::Cell8), *displacement, "8-cell");
+        assert!((volume - 1.0).abs() < 1.0e-4);
+    }
+}
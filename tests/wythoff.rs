@@ -0,0 +1,135 @@
+extern crate four;
+
+use four::mesh::Mesh;
+use four::wythoff::{CoxeterGroup, UniformPolychoron};
+
+/// Checks the number of vertices, edges, faces, and cells of a uniform polychoron, and
+/// that its topology is valid.
+fn assert_counts(group: CoxeterGroup, rings: [bool; 4], expected: [usize; 4]) {
+    let mesh = Mesh::new(UniformPolychoron::new(group, rings));
+    let counts = [
+        mesh.get_number_of_vertices(),
+        mesh.get_number_of_edges(),
+        mesh.get_number_of_faces(),
+        mesh.get_cell_faces().len(),
+    ];
+
+    assert_eq!(counts, expected, "{:?} {:?}", group, rings);
+    assert_eq!(mesh.validate(), Ok(()), "{:?} {:?}", group, rings);
+}
+
+#[test]
+fn a4_polychora() {
+    // 5-cell, rectified 5-cell, runcinated 5-cell, and omnitruncated 5-cell
+    assert_counts(
+        CoxeterGroup::A4,
+        [true, false, false, false],
+        [5, 10, 10, 5],
+    );
+    assert_counts(
+        CoxeterGroup::A4,
+        [false, true, false, false],
+        [10, 30, 30, 10],
+    );
+    assert_counts(
+        CoxeterGroup::A4,
+        [true, false, false, true],
+        [20, 60, 70, 30],
+    );
+    assert_counts(
+        CoxeterGroup::A4,
+        [true, true, true, true],
+        [120, 240, 150, 30],
+    );
+}
+
+#[test]
+fn b4_polychora() {
+    // 8-cell, 16-cell, truncated 8-cell, and bitruncated 8-cell
+    assert_counts(
+        CoxeterGroup::B4,
+        [true, false, false, false],
+        [16, 32, 24, 8],
+    );
+    assert_counts(
+        CoxeterGroup::B4,
+        [false, false, false, true],
+        [8, 24, 32, 16],
+    );
+    assert_counts(
+        CoxeterGroup::B4,
+        [true, true, false, false],
+        [64, 128, 88, 24],
+    );
+    assert_counts(
+        CoxeterGroup::B4,
+        [false, true, true, false],
+        [96, 192, 120, 24],
+    );
+}
+
+#[test]
+fn d4_polychora() {
+    // Demitesseract (16-cell), rectified demitesseract (24-cell), and the truncated
+    // demitesseract (truncated 16-cell)
+    assert_counts(
+        CoxeterGroup::D4,
+        [true, false, false, false],
+        [8, 24, 32, 16],
+    );
+    assert_counts(
+        CoxeterGroup::D4,
+        [false, true, false, false],
+        [24, 96, 96, 24],
+    );
+    assert_counts(
+        CoxeterGroup::D4,
+        [true, true, false, false],
+        [48, 120, 96, 24],
+    );
+}
+
+#[test]
+fn f4_polychora() {
+    // 24-cell, rectified 24-cell, bitruncated 24-cell, and runcinated 24-cell
+    assert_counts(
+        CoxeterGroup::F4,
+        [true, false, false, false],
+        [24, 96, 96, 24],
+    );
+    assert_counts(
+        CoxeterGroup::F4,
+        [false, true, false, false],
+        [96, 288, 240, 48],
+    );
+    assert_counts(
+        CoxeterGroup::F4,
+        [false, true, true, false],
+        [288, 576, 336, 48],
+    );
+    assert_counts(
+        CoxeterGroup::F4,
+        [true, false, false, true],
+        [144, 576, 672, 240],
+    );
+}
+
+#[test]
+fn h4_polychora() {
+    // 120-cell, 600-cell, and rectified 600-cell
+    assert_counts(
+        CoxeterGroup::H4,
+        [true, false, false, false],
+        [600, 1200, 720, 120],
+    );
+    assert_counts(
+        CoxeterGroup::H4,
+        [false, false, false, true],
+        [120, 720, 1200, 600],
+    );
+    assert_counts(
+        CoxeterGroup::H4,
+        [false, false, true, false],
+        [720, 3600, 3600, 720],
+    );
+}